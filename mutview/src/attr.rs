@@ -0,0 +1,278 @@
+//! Parsing of `#[zordon(...)]` attributes on structs, enums, fields and variants.
+
+use syn::{Attribute, Lit, LitByteStr, LitInt, LitStr, Meta, NestedMeta};
+
+/// Returns the items of every `#[zordon(...)]` attribute in `attrs`.
+fn zordon_items(attrs: &[Attribute]) -> syn::Result<Vec<NestedMeta>> {
+    let mut items = Vec::new();
+
+    for attr in attrs.iter().filter(|a| a.path.is_ident("zordon")) {
+        match attr.parse_meta()? {
+            Meta::List(list) => items.extend(list.nested),
+            meta => return Err(syn::Error::new_spanned(meta, "expected #[zordon(...)]")),
+        }
+    }
+
+    Ok(items)
+}
+
+/// How the length of a runtime length field is given.
+pub enum Length {
+    /// `count = ...`, the number of elements.
+    Count(Lit),
+    /// `len = ...`, the number of bytes.
+    Len(Lit),
+}
+
+/// The value a field must hold for the view to parse.
+pub enum Expected {
+    /// `magic = b"..."`, the bytes of an array field.
+    Magic(LitByteStr),
+    /// `eq = ...`, the value of an integer field.
+    Eq(Lit),
+    /// `reserved`, every bit of the field is zero.
+    Reserved,
+}
+
+/// Where the offset of an alignment attribute is measured from.
+#[derive(Clone, Copy)]
+pub enum Align {
+    /// `align = N`, from the start of the struct.
+    Struct(usize),
+    /// `abs_align = N`, from the start of the buffer, as given by the `ParseCtx` offset.
+    Buffer(usize),
+}
+
+impl Align {
+    fn parse(nv: &syn::MetaNameValue) -> syn::Result<Self> {
+        let align = match &nv.lit {
+            Lit::Int(int) => int.base10_parse::<usize>()?,
+            lit => return Err(syn::Error::new_spanned(lit, "expected an integer")),
+        };
+
+        match align {
+            0 => Err(syn::Error::new_spanned(
+                &nv.lit,
+                "alignment must be at least 1",
+            )),
+            a if nv.path.is_ident("abs_align") => Ok(Align::Buffer(a)),
+            a => Ok(Align::Struct(a)),
+        }
+    }
+}
+
+/// Where a field with an offset attribute starts.
+pub enum At {
+    /// `at = ...`, an offset from the start of the struct.
+    Struct(Lit),
+    /// `abs_at = ...`, an offset from the start of the buffer, as given by the `ParseCtx` offset.
+    Buffer(Lit),
+}
+
+/// Options set on a field via `#[zordon(...)]`.
+#[derive(Default)]
+pub struct FieldAttrs {
+    /// The field views the same bytes as the previous field instead of consuming new ones.
+    pub shared: bool,
+    /// The length of a runtime length field such as a `VarArrayView`.
+    pub length: Option<Length>,
+    /// Predicate deciding whether an `Option` field is parsed.
+    pub cond: Option<LitStr>,
+    /// The tag selecting the variant of a tagged union field.
+    pub tag: Option<Lit>,
+    /// The value checked once the field is parsed.
+    pub expected: Option<Expected>,
+    /// Offset the field starts at, skipping any bytes before it.
+    pub at: Option<At>,
+    /// Bytes skipped before the field, after seeking to any `at`.
+    pub pad: Option<Lit>,
+    /// Alignment of the field's offset, applied after any `pad`.
+    pub align: Option<Align>,
+}
+
+impl FieldAttrs {
+    /// Collects every `#[zordon(...)]` attribute on `field`.
+    pub fn parse(field: &syn::Field) -> syn::Result<Self> {
+        let mut attrs = Self::default();
+
+        for nested in zordon_items(&field.attrs)?.iter() {
+            match nested {
+                NestedMeta::Meta(Meta::Path(p)) if p.is_ident("shared") => attrs.shared = true,
+                NestedMeta::Meta(Meta::Path(p)) if p.is_ident("reserved") => {
+                    attrs.set_expected(nested, Expected::Reserved)?
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("count") => {
+                    attrs.set_length(nested, Length::Count(nv.lit.clone()))?
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("len") => {
+                    attrs.set_length(nested, Length::Len(nv.lit.clone()))?
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("if") => match &nv.lit {
+                    Lit::Str(s) => attrs.cond = Some(s.clone()),
+                    lit => return Err(syn::Error::new_spanned(lit, "expected a string")),
+                },
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("tag") => {
+                    attrs.tag = Some(nv.lit.clone())
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("magic") => {
+                    match &nv.lit {
+                        Lit::ByteStr(b) => {
+                            attrs.set_expected(nested, Expected::Magic(b.clone()))?
+                        }
+                        lit => return Err(syn::Error::new_spanned(lit, "expected a byte string")),
+                    }
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("eq") => match &nv.lit {
+                    Lit::Int(_) | Lit::Float(_) | Lit::Bool(_) => {
+                        attrs.set_expected(nested, Expected::Eq(nv.lit.clone()))?
+                    }
+                    lit => return Err(syn::Error::new_spanned(lit, "expected a number or a bool")),
+                },
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("at") => {
+                    attrs.at = Some(At::Struct(nv.lit.clone()))
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("abs_at") => {
+                    attrs.at = Some(At::Buffer(nv.lit.clone()))
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("pad") => {
+                    attrs.pad = Some(nv.lit.clone())
+                }
+                NestedMeta::Meta(Meta::NameValue(nv))
+                    if nv.path.is_ident("align") || nv.path.is_ident("abs_align") =>
+                {
+                    attrs.align = Some(Align::parse(nv)?)
+                }
+                _ => return Err(syn::Error::new_spanned(nested, "unknown zordon attribute")),
+            }
+        }
+
+        let padded = attrs.at.is_some() || attrs.pad.is_some() || attrs.align.is_some();
+        if padded && (attrs.shared || attrs.cond.is_some()) {
+            return Err(syn::Error::new_spanned(
+                field,
+                "a shared field or a field with an if attribute can't have an at, pad or align",
+            ));
+        }
+
+        if attrs.shared && (attrs.length.is_some() || attrs.cond.is_some() || attrs.tag.is_some()) {
+            return Err(syn::Error::new_spanned(
+                field,
+                "a shared field can't have a count, len, if or tag",
+            ));
+        }
+
+        if let (Some(_), Some(Length::Count(_))) = (&attrs.tag, &attrs.length) {
+            return Err(syn::Error::new_spanned(
+                field,
+                "a tagged field can be given a len but not a count",
+            ));
+        }
+
+        Ok(attrs)
+    }
+
+    fn set_length(&mut self, nested: &NestedMeta, length: Length) -> syn::Result<()> {
+        if self.length.is_some() {
+            return Err(syn::Error::new_spanned(
+                nested,
+                "only one of count and len can be given",
+            ));
+        }
+
+        self.length = Some(length);
+        Ok(())
+    }
+
+    fn set_expected(&mut self, nested: &NestedMeta, expected: Expected) -> syn::Result<()> {
+        if self.expected.is_some() {
+            return Err(syn::Error::new_spanned(
+                nested,
+                "only one of magic, eq and reserved can be given",
+            ));
+        }
+
+        self.expected = Some(expected);
+        Ok(())
+    }
+}
+
+/// Options set on a struct via `#[zordon(...)]`.
+#[derive(Default)]
+pub struct StructAttrs {
+    /// Alignment of the end of the struct, padding it after the last field.
+    pub align: Option<Align>,
+}
+
+impl StructAttrs {
+    /// Collects every `#[zordon(...)]` attribute on the struct.
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut struct_attrs = Self::default();
+
+        for nested in zordon_items(attrs)?.iter() {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(nv))
+                    if nv.path.is_ident("align") || nv.path.is_ident("abs_align") =>
+                {
+                    struct_attrs.align = Some(Align::parse(nv)?)
+                }
+                _ => return Err(syn::Error::new_spanned(nested, "unknown zordon attribute")),
+            }
+        }
+
+        Ok(struct_attrs)
+    }
+}
+
+/// Options set on an enum via `#[zordon(...)]`.
+#[derive(Default)]
+pub struct EnumAttrs {
+    /// The type of the tag preceding the variant, if the enum parses its own tag.
+    pub tag_type: Option<syn::Type>,
+}
+
+impl EnumAttrs {
+    /// Collects every `#[zordon(...)]` attribute on the enum.
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut enum_attrs = Self::default();
+
+        for nested in zordon_items(attrs)?.iter() {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("tag_type") => {
+                    match &nv.lit {
+                        Lit::Str(s) => enum_attrs.tag_type = Some(s.parse()?),
+                        lit => return Err(syn::Error::new_spanned(lit, "expected a type string")),
+                    }
+                }
+                _ => return Err(syn::Error::new_spanned(nested, "unknown zordon attribute")),
+            }
+        }
+
+        Ok(enum_attrs)
+    }
+}
+
+/// Options set on an enum variant via `#[zordon(...)]`.
+#[derive(Default)]
+pub struct VariantAttrs {
+    /// The tags selecting the variant. A variant without tags is the fallback for unknown tags.
+    pub tags: Vec<LitInt>,
+}
+
+impl VariantAttrs {
+    /// Collects every `#[zordon(...)]` attribute on `variant`.
+    pub fn parse(variant: &syn::Variant) -> syn::Result<Self> {
+        let mut attrs = Self::default();
+
+        for nested in zordon_items(&variant.attrs)?.iter() {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("tag") => match &nv.lit {
+                    Lit::Int(int) => attrs.tags.push(int.clone()),
+                    lit => return Err(syn::Error::new_spanned(lit, "expected an integer")),
+                },
+                _ => return Err(syn::Error::new_spanned(nested, "unknown zordon attribute")),
+            }
+        }
+
+        Ok(attrs)
+    }
+}
@@ -0,0 +1,194 @@
+//! Compilation of the expressions used by `count`, `len` and `if` attributes.
+//!
+//! Expressions are made of integer literals, earlier fields (read via `LenValue`), constants,
+//! arithmetic, bitwise, comparison and logical operators, and parentheses. A name which isn't a
+//! field of the struct is read as a constant in scope, e.g. `HEADER_LEN` or `consts::HEADER_LEN`,
+//! while naming the field itself or a later field is a compile error as it isn't parsed yet.
+//! Integer arithmetic is done on `usize` and is checked, so overflow, underflow and division by
+//! zero return a `LengthOverflow` error from the constructor rather than panicking. A negative or
+//! too large field or constant returns an `InvalidLength` error.
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{BinOp, Expr, Ident, Lit, UnOp};
+
+/// The names of the struct's fields, split at the field whose attribute is being compiled.
+#[derive(Clone, Copy)]
+pub struct Scope<'f> {
+    /// The fields parsed before the field, which can be read.
+    earlier: &'f [&'f Ident],
+    /// The field itself and the fields after it, which aren't parsed yet.
+    later: &'f [&'f Ident],
+}
+
+impl<'f> Scope<'f> {
+    /// Returns the scope of the attributes of field `index` of `names`.
+    pub fn new(names: &'f [&'f Ident], index: usize) -> Self {
+        let (earlier, later) = names.split_at(index);
+
+        Self { earlier, later }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Int,
+    Bool,
+}
+
+/// Returns an expression evaluating to the `usize` value of a `count`/`len` attribute, which is
+/// either an integer literal or an expression string.
+pub fn length_value(lit: &Lit, scope: Scope) -> syn::Result<TokenStream> {
+    match lit {
+        Lit::Int(int) => {
+            let v = proc_macro2::Literal::usize_suffixed(int.base10_parse()?);
+            Ok(quote! {#v})
+        }
+        Lit::Str(s) => expect(&s.parse::<Expr>()?, Kind::Int, scope),
+        _ => Err(syn::Error::new_spanned(
+            lit,
+            "expected an integer or a length expression",
+        )),
+    }
+}
+
+/// Returns an expression evaluating to the `bool` value of an `if` attribute.
+pub fn predicate(lit: &syn::LitStr, scope: Scope) -> syn::Result<TokenStream> {
+    expect(&lit.parse::<Expr>()?, Kind::Bool, scope)
+}
+
+fn expect(expr: &Expr, kind: Kind, scope: Scope) -> syn::Result<TokenStream> {
+    let (k, tokens) = compile(expr, scope)?;
+
+    match (k, kind) {
+        (k, kind) if k == kind => Ok(tokens),
+        (_, Kind::Int) => Err(syn::Error::new_spanned(
+            expr,
+            "expected an integer expression",
+        )),
+        (_, Kind::Bool) => Err(syn::Error::new_spanned(
+            expr,
+            "expected a boolean expression",
+        )),
+    }
+}
+
+fn overflow(op: TokenStream) -> TokenStream {
+    quote! {
+        #op.ok_or_else(|| __zordon_field_ctx.error(::zordon::error::ViewError::length_overflow()))?
+    }
+}
+
+fn compile(expr: &Expr, scope: Scope) -> syn::Result<(Kind, TokenStream)> {
+    match expr {
+        Expr::Lit(l) => match &l.lit {
+            Lit::Int(int) => {
+                let v = proc_macro2::Literal::usize_suffixed(int.base10_parse()?);
+                Ok((Kind::Int, quote! {#v}))
+            }
+            Lit::Bool(b) => Ok((Kind::Bool, quote! {#b})),
+            lit => Err(syn::Error::new_spanned(
+                lit,
+                "expected an integer or a bool",
+            )),
+        },
+        Expr::Path(p) if p.qself.is_none() => match p.path.get_ident() {
+            Some(field) if scope.earlier.contains(&field) => Ok((
+                Kind::Int,
+                quote! {
+                    ::zordon::types::LenValue::len_value(&#field)
+                        .ok_or_else(|| __zordon_field_ctx.error(::zordon::error::ViewError::invalid_length()))?
+                },
+            )),
+            Some(field) if scope.later.contains(&field) => Err(syn::Error::new_spanned(
+                field,
+                format!("`{}` isn't parsed before this field", field),
+            )),
+            _ => {
+                let path = &p.path;
+
+                Ok((
+                    Kind::Int,
+                    quote! {
+                        <usize as ::core::convert::TryFrom<_>>::try_from(#path)
+                            .ok()
+                            .ok_or_else(|| __zordon_field_ctx.error(::zordon::error::ViewError::invalid_length()))?
+                    },
+                ))
+            }
+        },
+        Expr::Paren(p) => compile(&p.expr, scope),
+        Expr::Unary(u) => match u.op {
+            UnOp::Not(_) => {
+                let inner = expect(&u.expr, Kind::Bool, scope)?;
+                Ok((Kind::Bool, quote! {!(#inner)}))
+            }
+            _ => Err(syn::Error::new_spanned(
+                u,
+                "only ! is supported as a unary operator",
+            )),
+        },
+        Expr::Binary(b) => {
+            let (lk, l) = compile(&b.left, scope)?;
+            let (rk, r) = compile(&b.right, scope)?;
+            if lk != rk {
+                return Err(syn::Error::new_spanned(
+                    b,
+                    "mismatched integer and boolean operands",
+                ));
+            }
+
+            let int = |kind: Kind, tokens: TokenStream| match lk {
+                Kind::Int => Ok((kind, tokens)),
+                Kind::Bool => Err(syn::Error::new_spanned(b, "expected integer operands")),
+            };
+            let boolean = |tokens: TokenStream| match lk {
+                Kind::Bool => Ok((Kind::Bool, tokens)),
+                Kind::Int => Err(syn::Error::new_spanned(b, "expected boolean operands")),
+            };
+
+            match b.op {
+                BinOp::Add(_) => int(Kind::Int, overflow(quote! {usize::checked_add(#l, #r)})),
+                BinOp::Sub(_) => int(Kind::Int, overflow(quote! {usize::checked_sub(#l, #r)})),
+                BinOp::Mul(_) => int(Kind::Int, overflow(quote! {usize::checked_mul(#l, #r)})),
+                BinOp::Div(_) => int(Kind::Int, overflow(quote! {usize::checked_div(#l, #r)})),
+                BinOp::Rem(_) => int(Kind::Int, overflow(quote! {usize::checked_rem(#l, #r)})),
+                BinOp::Shl(_) => int(
+                    Kind::Int,
+                    // checked_shl only rejects oversized shifts, so also reject any shift that
+                    // drops set bits off the top.
+                    overflow(quote! {{
+                        let (l, r): (usize, u32) =
+                            (#l, ::core::convert::TryFrom::try_from(#r).unwrap_or(u32::MAX));
+                        usize::checked_shl(l, r).filter(|v| v >> r == l)
+                    }}),
+                ),
+                BinOp::Shr(_) => int(
+                    Kind::Int,
+                    overflow(quote! {
+                        usize::checked_shr(#l, ::core::convert::TryFrom::try_from(#r).unwrap_or(u32::MAX))
+                    }),
+                ),
+                BinOp::BitAnd(_) => Ok((lk, quote! {((#l) & (#r))})),
+                BinOp::BitOr(_) => Ok((lk, quote! {((#l) | (#r))})),
+                BinOp::BitXor(_) => Ok((lk, quote! {((#l) ^ (#r))})),
+                BinOp::Eq(_) => Ok((Kind::Bool, quote! {((#l) == (#r))})),
+                BinOp::Ne(_) => Ok((Kind::Bool, quote! {((#l) != (#r))})),
+                BinOp::Lt(_) => int(Kind::Bool, quote! {((#l) < (#r))}),
+                BinOp::Le(_) => int(Kind::Bool, quote! {((#l) <= (#r))}),
+                BinOp::Gt(_) => int(Kind::Bool, quote! {((#l) > (#r))}),
+                BinOp::Ge(_) => int(Kind::Bool, quote! {((#l) >= (#r))}),
+                BinOp::And(_) => boolean(quote! {((#l) && (#r))}),
+                BinOp::Or(_) => boolean(quote! {((#l) || (#r))}),
+                _ => Err(syn::Error::new_spanned(
+                    b.op,
+                    "compound assignment isn't supported in expressions",
+                )),
+            }
+        }
+        _ => Err(syn::Error::new_spanned(
+            expr,
+            "expected an integer, a bool, an earlier field, a constant or an operator expression",
+        )),
+    }
+}
@@ -0,0 +1,970 @@
+extern crate proc_macro;
+mod attr;
+mod expr;
+
+use attr::{Align, At, EnumAttrs, Expected, FieldAttrs, Length, StructAttrs, VariantAttrs};
+use expr::{length_value, predicate, Scope};
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, punctuated::Punctuated, DeriveInput};
+
+/// Returns T if `ty` is `Option<T>`.
+fn option_inner(ty: &syn::Type) -> Option<&syn::Type> {
+    let seg = match ty {
+        syn::Type::Path(p) if p.qself.is_none() => p.path.segments.last()?,
+        _ => return None,
+    };
+
+    match &seg.arguments {
+        syn::PathArguments::AngleBracketed(args) if seg.ident == "Option" => {
+            match args.args.first()? {
+                syn::GenericArgument::Type(t) => Some(t),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Returns the inherent `mut_view` and `view` constructors, which forward to the type's
+/// TryMutView and TryView impls.
+fn constructors(
+    name: &syn::Ident,
+    mut_bounds: &[TokenStream2],
+    ref_bounds: &[TokenStream2],
+) -> TokenStream2 {
+    quote! {
+        impl<'a> #name<'a>
+        where
+            #(#mut_bounds,)*
+        {
+            pub fn mut_view(__zordon_buf: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+                    match Self::try_mut_view(__zordon_buf) {
+                        Ok(v) => v,
+                        Err(e) => panic!("{}", e),
+                    }
+            }
+
+            pub fn try_mut_view(
+                __zordon_buf: &'a mut [u8],
+            ) -> ::core::result::Result<(Self, &'a mut [u8]), ::zordon::error::ViewError> {
+                    Self::try_mut_view_ctx(__zordon_buf, &::zordon::error::ParseCtx::default())
+            }
+
+            pub fn try_mut_view_ctx(
+                __zordon_buf: &'a mut [u8],
+                __zordon_ctx: &::zordon::error::ParseCtx,
+            ) -> ::core::result::Result<(Self, &'a mut [u8]), ::zordon::error::ViewError> {
+                    <Self as ::zordon::types::TryMutView<'a>>::try_mut_view_ctx(__zordon_buf, __zordon_ctx)
+            }
+
+            pub fn mut_view_endian(
+                __zordon_buf: &'a mut [u8],
+                endian: ::zordon::types::Endian,
+            ) -> (Self, &'a mut [u8]) {
+                    match Self::try_mut_view_endian(__zordon_buf, endian) {
+                        Ok(v) => v,
+                        Err(e) => panic!("{}", e),
+                    }
+            }
+
+            pub fn try_mut_view_endian(
+                __zordon_buf: &'a mut [u8],
+                endian: ::zordon::types::Endian,
+            ) -> ::core::result::Result<(Self, &'a mut [u8]), ::zordon::error::ViewError> {
+                    let __zordon_ctx = ::zordon::error::ParseCtx::default().with_endian(endian);
+
+                    Self::try_mut_view_ctx(__zordon_buf, &__zordon_ctx)
+            }
+        }
+
+        impl<'a> #name<'a>
+        where
+            #(#ref_bounds,)*
+        {
+            pub fn view(__zordon_buf: &'a [u8]) -> (Self, &'a [u8]) {
+                    match Self::try_view(__zordon_buf) {
+                        Ok(v) => v,
+                        Err(e) => panic!("{}", e),
+                    }
+            }
+
+            pub fn try_view(
+                __zordon_buf: &'a [u8],
+            ) -> ::core::result::Result<(Self, &'a [u8]), ::zordon::error::ViewError> {
+                    Self::try_view_ctx(__zordon_buf, &::zordon::error::ParseCtx::default())
+            }
+
+            pub fn try_view_ctx(
+                __zordon_buf: &'a [u8],
+                __zordon_ctx: &::zordon::error::ParseCtx,
+            ) -> ::core::result::Result<(Self, &'a [u8]), ::zordon::error::ViewError> {
+                    <Self as ::zordon::types::TryView<'a>>::try_view_ctx(__zordon_buf, __zordon_ctx)
+            }
+
+            pub fn view_endian(
+                __zordon_buf: &'a [u8],
+                endian: ::zordon::types::Endian,
+            ) -> (Self, &'a [u8]) {
+                    match Self::try_view_endian(__zordon_buf, endian) {
+                        Ok(v) => v,
+                        Err(e) => panic!("{}", e),
+                    }
+            }
+
+            pub fn try_view_endian(
+                __zordon_buf: &'a [u8],
+                endian: ::zordon::types::Endian,
+            ) -> ::core::result::Result<(Self, &'a [u8]), ::zordon::error::ViewError> {
+                    let __zordon_ctx = ::zordon::error::ParseCtx::default().with_endian(endian);
+
+                    Self::try_view_ctx(__zordon_buf, &__zordon_ctx)
+            }
+        }
+    }
+}
+
+#[proc_macro_derive(MutView, attributes(zordon))]
+pub fn derive(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let expanded = match ast.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(syn::FieldsNamed { ref named, .. }),
+            ..
+        }) => derive_struct(&ast, named),
+        syn::Data::Enum(syn::DataEnum { ref variants, .. }) => derive_enum(&ast, variants),
+        _ => panic!("Macro must be applied to struct or enum"),
+    };
+
+    match expanded {
+        Ok(expanded) => expanded.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// Returns statements moving `__zordon_buf` past `amount` bytes of padding. `split` is `split_at_mut` or
+/// `split_at` and `ctx` the context blamed if the buffer is too short.
+fn skip(split: &TokenStream2, amount: TokenStream2, ctx: TokenStream2) -> TokenStream2 {
+    quote! {
+        let __zordon_skip: usize = #amount;
+        if __zordon_skip > __zordon_buf.len() {
+            return Err(#ctx.error(::zordon::error::ViewError::new(__zordon_skip, __zordon_buf.len())));
+        }
+        let __zordon_buf = __zordon_buf.#split(__zordon_skip).1;
+    }
+}
+
+/// Returns the number of bytes needed to move the current position to the offset of an `at`
+/// attribute, failing with an overlap error if the offset is behind the current position.
+fn at_amount(at: &At, scope: Scope) -> syn::Result<TokenStream2> {
+    let abs_at = match at {
+        At::Struct(lit) => {
+            let at = length_value(lit, scope)?;
+            quote! {__zordon_ctx.offset().saturating_add(#at)}
+        }
+        At::Buffer(lit) => length_value(lit, scope)?,
+    };
+
+    Ok(quote! {{
+        let __zordon_abs_at: usize = #abs_at;
+        match __zordon_abs_at.checked_sub(__zordon_ctx.offset().saturating_add(__zordon_len - __zordon_buf.len())) {
+            Some(skip) => skip,
+            None => {
+                return Err(::zordon::error::ViewError {
+                    path: __zordon_field_ctx.path(),
+                    ..::zordon::error::ViewError::overlap().offset_by(__zordon_abs_at)
+                })
+            }
+        }
+    }})
+}
+
+/// Returns the number of bytes needed to move the current position to `align`.
+fn align_amount(align: Align) -> TokenStream2 {
+    let (align, pos) = match align {
+        Align::Struct(a) => (a, quote! {(__zordon_len - __zordon_buf.len())}),
+        Align::Buffer(a) => (
+            a,
+            quote! {__zordon_ctx.offset().wrapping_add(__zordon_len - __zordon_buf.len())},
+        ),
+    };
+    let align = proc_macro2::Literal::usize_suffixed(align);
+
+    quote! {(#align - #pos % #align) % #align}
+}
+
+/// Returns the `OFFSET_<FIELD>` constants of the fields whose offset is known at compile time, and
+/// the `SIZE` constant and FixedSize impl if every field's length and position is.
+fn layout(
+    struct_name: &syn::Ident,
+    fields: &Punctuated<syn::Field, syn::Token![,]>,
+    attrs: &[FieldAttrs],
+    struct_attrs: &StructAttrs,
+    is_option: impl Fn(&syn::Field, &FieldAttrs) -> bool,
+) -> syn::Result<TokenStream2> {
+    let round_up = |pos: TokenStream2, align: usize| {
+        let align = proc_macro2::Literal::usize_suffixed(align);
+        quote! {(#pos + (#align - #pos % #align) % #align)}
+    };
+
+    let mut consts = Vec::new();
+    let mut bounds = Vec::new();
+    // The offset after the last field, or None once it is only known at runtime.
+    let mut end = Some(quote! {0usize});
+    let mut prev = None;
+
+    for (f, a) in fields.iter().zip(attrs) {
+        let mut pos = match (&end, &a.at) {
+            (Some(end), None) => end.clone(),
+            _ => {
+                end = None;
+                break;
+            }
+        };
+
+        match &a.pad {
+            Some(syn::Lit::Int(pad)) => {
+                let pad = proc_macro2::Literal::usize_suffixed(pad.base10_parse()?);
+                pos = quote! {(#pos + #pad)};
+            }
+            Some(_) => {
+                end = None;
+                break;
+            }
+            None => {}
+        }
+
+        match a.align {
+            Some(Align::Struct(align)) => pos = round_up(pos, align),
+            Some(Align::Buffer(_)) => {
+                end = None;
+                break;
+            }
+            None => {}
+        }
+
+        let name = f.ident.as_ref().map(|n| n.to_string()).unwrap_or_default();
+        let name = name.trim_start_matches("r#");
+        let const_name = format_ident!("OFFSET_{}", name.to_uppercase());
+        let doc = format!(
+            "Offset of the `{}` field from the start of the struct.",
+            name
+        );
+        let offset = match &prev {
+            Some(prev) if a.shared => quote! {Self::#prev},
+            _ => pos,
+        };
+
+        // Each offset only needs the sizes of the fields before it.
+        consts.push(quote! {
+            impl<'a> #struct_name<'a>
+            where
+                #(#bounds,)*
+            {
+                #[doc = #doc]
+                pub const #const_name: usize = #offset;
+            }
+        });
+        prev = Some(const_name.clone());
+
+        if a.shared || is_option(f, a) {
+            continue;
+        }
+        if a.length.is_some() || a.cond.is_some() || a.tag.is_some() {
+            end = None;
+            break;
+        }
+
+        let ty = &f.ty;
+        bounds.push(quote! {#ty: ::zordon::types::FixedSize});
+        end = Some(quote! {(Self::#const_name + <#ty as ::zordon::types::FixedSize>::SIZE)});
+    }
+
+    let size = match (end, struct_attrs.align) {
+        (Some(end), Some(Align::Struct(align))) => Some(round_up(end, align)),
+        (Some(_), Some(Align::Buffer(_))) | (None, _) => None,
+        (Some(end), None) => Some(end),
+    };
+
+    if consts.is_empty() && size.is_none() {
+        return Ok(quote! {});
+    }
+
+    let size = size.map(|size| {
+        quote! {
+            impl<'a> #struct_name<'a>
+            where
+                #(#bounds,)*
+            {
+                /// Number of bytes viewed by the struct.
+                pub const SIZE: usize = #size;
+            }
+
+            impl<'a> ::zordon::types::FixedSize for #struct_name<'a>
+            where
+                #(#bounds,)*
+            {
+                const SIZE: usize = #size;
+            }
+        }
+    });
+
+    Ok(quote! {
+        #(#consts)*
+        #size
+    })
+}
+
+fn derive_struct(
+    ast: &DeriveInput,
+    fields: &Punctuated<syn::Field, syn::Token![,]>,
+) -> syn::Result<TokenStream2> {
+    let struct_name = &ast.ident;
+    let struct_attrs = StructAttrs::parse(&ast.attrs)?;
+    let attrs = fields
+        .iter()
+        .map(FieldAttrs::parse)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    if let (Some(f), Some(a)) = (fields.first(), attrs.first()) {
+        if a.shared {
+            return Err(syn::Error::new_spanned(
+                f,
+                "the first field has no previous field to share",
+            ));
+        }
+    }
+
+    let names = fields
+        .iter()
+        .filter_map(|f| f.ident.as_ref())
+        .collect::<Vec<_>>();
+
+    // Option fields without an `if` attribute are always None and consume no bytes.
+    let is_option =
+        |f: &syn::Field, a: &FieldAttrs| a.cond.is_none() && option_inner(&f.ty).is_some();
+
+    // The type parsed from the buffer, which is T for a conditional Option<T> field.
+    let parsed_types = fields
+        .iter()
+        .zip(&attrs)
+        .map(|(f, a)| match a.cond {
+            Some(_) => option_inner(&f.ty).ok_or_else(|| {
+                syn::Error::new_spanned(&f.ty, "a field with an if attribute must be an Option")
+            }),
+            None => Ok(&f.ty),
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    // Bounds for the fields which are parsed from the buffer. Every one of these must implement
+    // TryMutView (or TryMutViewVar if its length is given by an attribute, or TryMutViewTagged if
+    // it has a tag) for the mut_view constructors and TryView (or TryViewVar, TryViewTagged) for
+    // the view constructors. `family` is "Mut" or "" as for `try_fields` below.
+    let bounds = |family: &str| {
+        let view = format_ident!("Try{}View", family);
+        let var = format_ident!("Try{}ViewVar", family);
+        let tagged = format_ident!("Try{}ViewTagged", family);
+
+        fields
+            .iter()
+            .zip(&attrs)
+            .zip(&parsed_types)
+            .filter(|((f, a), _)| !is_option(f, a) && !a.shared)
+            .map(|((_, a), ty)| match (&a.tag, &a.length) {
+                (Some(_), _) => quote! {#ty: ::zordon::types::#tagged<'a>},
+                (None, Some(_)) => quote! {#ty: ::zordon::types::#var<'a>},
+                (None, None) => quote! {#ty: ::zordon::types::#view<'a>},
+            })
+            .collect::<Vec<_>>()
+    };
+    let mut_bounds = bounds("Mut");
+    let ref_bounds = bounds("");
+
+    // `family` is "Mut" for the TryMutView constructors and "" for the TryView constructors.
+    let try_fields = |family: &str| {
+        let snake = match family {
+            "" => String::new(),
+            f => format!("{}_", f.to_lowercase()),
+        };
+        let view = format_ident!("Try{}View", family);
+        let var = format_ident!("Try{}ViewVar", family);
+        let ctor = format_ident!("try_{}view_ctx", snake);
+        let count_ctor = format_ident!("try_{}view_count_ctx", snake);
+        let len_ctor = format_ident!("try_{}view_len_ctx", snake);
+        let tagged = format_ident!("Try{}ViewTagged", family);
+        let tag_ctor = format_ident!("try_{}view_tag_ctx", snake);
+        let split = match family {
+            "" => quote! {split_at},
+            _ => quote! {split_at_mut},
+        };
+
+        fields
+            .iter()
+            .zip(&attrs)
+            .zip(&parsed_types)
+            .enumerate()
+            .map(|(i, ((f, a), ty))| {
+                let name = &f.ident;
+                let name_str = name.as_ref().map(|n| n.to_string());
+
+                if is_option(f, a) {
+                    if a.expected.is_some() || a.at.is_some() || a.pad.is_some() || a.align.is_some() {
+                        return Err(syn::Error::new_spanned(
+                            f,
+                            "an Option field needs an if attribute to have a magic, eq, reserved, at, pad or align",
+                        ));
+                    }
+
+                    let ty = &f.ty;
+                    return Ok(quote! {let #name = <#ty as ::core::default::Default>::default();});
+                }
+
+                // Fails the parse unless the value of `view` matches a magic or eq attribute, or
+                // is zero for a reserved field.
+                let check = |view: TokenStream2| {
+                    let expected = match &a.expected {
+                        Some(Expected::Magic(bytes)) => quote! {*#bytes},
+                        Some(Expected::Eq(lit)) => quote! {#lit},
+                        Some(Expected::Reserved) => {
+                            return quote! {
+                                if !__zordon_field_ctx.ignores_reserved() && !::zordon::types::IsZero::is_zero(#view) {
+                                    return Err(__zordon_field_ctx.error(::zordon::error::ViewError::reserved_not_zero()));
+                                }
+                            }
+                        }
+                        None => return quote! {},
+                    };
+
+                    quote! {
+                        ::zordon::types::EqValue::check_eq(#view, #expected).map_err(|e| __zordon_field_ctx.error(e))?;
+                    }
+                };
+
+                // Shared fields, such as bitfields packed into one word, view the previous
+                // field's bytes.
+                if a.shared {
+                    let prev = &fields[i - 1].ident;
+                    if a.expected.is_none() {
+                        return Ok(quote! {let #name = #prev.share();});
+                    }
+
+                    // The previous field's context starts at the shared bytes.
+                    let check = check(quote! {&#name});
+                    return Ok(quote! {
+                        let #name = #prev.share();
+                        let __zordon_field_ctx = __zordon_ctx.field(#name_str, __zordon_field_ctx.offset() - __zordon_ctx.offset());
+                        #check
+                    });
+                }
+
+                // Evaluates to the parsed view and the leftover slice.
+                let parse = match (&a.tag, &a.length) {
+                    (Some(tag), length) => {
+                        let tag = length_value(tag, Scope::new(&names, i))?;
+                        let len = match length {
+                            Some(Length::Len(lit)) => {
+                                let len = length_value(lit, Scope::new(&names, i))?;
+                                quote! {::core::option::Option::Some(#len)}
+                            }
+                            _ => quote! {::core::option::Option::None},
+                        };
+                        quote! {{
+                            let __zordon_tag: usize = #tag;
+                            let __zordon_byte_len: ::core::option::Option<usize> = #len;
+                            <#ty as ::zordon::types::#tagged<'a>>::#tag_ctor(__zordon_buf, __zordon_tag, __zordon_byte_len, &__zordon_field_ctx)?
+                        }}
+                    }
+                    (None, Some(Length::Count(lit))) => {
+                        let count = length_value(lit, Scope::new(&names, i))?;
+                        quote! {{
+                            let __zordon_count: usize = #count;
+                            <#ty as ::zordon::types::#var<'a>>::#count_ctor(__zordon_buf, __zordon_count, &__zordon_field_ctx)?
+                        }}
+                    }
+                    (None, Some(Length::Len(lit))) => {
+                        let len = length_value(lit, Scope::new(&names, i))?;
+                        quote! {{
+                            let __zordon_byte_len: usize = #len;
+                            <#ty as ::zordon::types::#var<'a>>::#len_ctor(__zordon_buf, __zordon_byte_len, &__zordon_field_ctx)?
+                        }}
+                    }
+                    (None, None) => quote! {
+                        <#ty as ::zordon::types::#view<'a>>::#ctor(__zordon_buf, &__zordon_field_ctx)?
+                    },
+                };
+
+                let parse = match &a.cond {
+                    Some(cond) => {
+                        let cond = predicate(cond, Scope::new(&names, i))?;
+                        quote! {
+                            if #cond {
+                                let (v, __zordon_buf) = #parse;
+                                (::core::option::Option::Some(v), __zordon_buf)
+                            } else {
+                                (::core::option::Option::None, __zordon_buf)
+                            }
+                        }
+                    }
+                    None => parse,
+                };
+
+                let check = match &a.cond {
+                    Some(_) if a.expected.is_some() => {
+                        let check = check(quote! {v});
+                        quote! {
+                            if let ::core::option::Option::Some(v) = &#name {
+                                #check
+                            }
+                        }
+                    }
+                    _ => check(quote! {&#name}),
+                };
+
+                // Bytes up to an at offset and padding are skipped before the field, blaming the
+                // field if the buffer ends.
+                let at = match &a.at {
+                    Some(at) => skip(&split, at_amount(at, Scope::new(&names, i))?, quote! {__zordon_field_ctx}),
+                    None => quote! {},
+                };
+                let pad = match &a.pad {
+                    Some(lit) => skip(&split, length_value(lit, Scope::new(&names, i))?, quote! {__zordon_field_ctx}),
+                    None => quote! {},
+                };
+                let align = match a.align {
+                    Some(align) => skip(&split, align_amount(align), quote! {__zordon_field_ctx}),
+                    None => quote! {},
+                };
+                let padding = match (&a.at, &a.pad, &a.align) {
+                    (None, None, None) => quote! {},
+                    _ => quote! {
+                        let __zordon_field_ctx = __zordon_ctx.field(#name_str, __zordon_len - __zordon_buf.len());
+                        #at
+                        #pad
+                        #align
+                    },
+                };
+
+                Ok(quote! {
+                    #padding
+                    let __zordon_field_ctx = __zordon_ctx.field(#name_str, __zordon_len - __zordon_buf.len());
+                    let (#name, __zordon_buf) = #parse;
+                    #check
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()
+    };
+
+    // Pads the end of the struct to its alignment.
+    let end_align = |family: &str| match struct_attrs.align {
+        Some(align) => {
+            let split = match family {
+                "" => quote! {split_at},
+                _ => quote! {split_at_mut},
+            };
+
+            skip(
+                &split,
+                align_amount(align),
+                quote! {__zordon_ctx.advance(__zordon_len - __zordon_buf.len())},
+            )
+        }
+        None => quote! {},
+    };
+    let mut_end_align = end_align("Mut");
+    let ref_end_align = end_align("");
+    let try_mut_fields = try_fields("Mut")?;
+    let try_ref_fields = try_fields("")?;
+
+    let field_names = fields
+        .iter()
+        .map(|f| {
+            let name = &f.ident;
+
+            quote! {#name}
+        })
+        .collect::<Vec<_>>();
+
+    let ctors = constructors(struct_name, &mut_bounds, &ref_bounds);
+    let layout = layout(struct_name, fields, &attrs, &struct_attrs, is_option)?;
+
+    // Zeroes the reserved fields, which must be parsed fields.
+    let reserved = fields
+        .iter()
+        .zip(&attrs)
+        .zip(&parsed_types)
+        .filter(|((_, a), _)| matches!(a.expected, Some(Expected::Reserved)))
+        .collect::<Vec<_>>();
+    let sanitize = if reserved.is_empty() {
+        quote! {}
+    } else {
+        let bounds = reserved
+            .iter()
+            .map(|(_, ty)| quote! {#ty: ::zordon::types::Sanitize})
+            .collect::<Vec<_>>();
+        let zero = reserved.iter().map(|((f, a), _)| {
+            let name = &f.ident;
+
+            match a.cond {
+                Some(_) => quote! {
+                    if let ::core::option::Option::Some(v) = &mut self.#name {
+                        ::zordon::types::Sanitize::sanitize(v)
+                    }
+                },
+                None => quote! {::zordon::types::Sanitize::sanitize(&mut self.#name)},
+            }
+        });
+
+        quote! {
+            impl<'a> ::zordon::types::Sanitize for #struct_name<'a>
+            where
+                #(#bounds,)*
+            {
+                fn sanitize(&mut self) {
+                    #(#zero;)*
+                }
+            }
+        }
+    };
+
+    Ok(quote! {
+        #ctors
+        #sanitize
+        #layout
+
+        impl<'a> ::zordon::types::TryMutView<'a> for #struct_name<'a>
+        where
+            #(#mut_bounds,)*
+        {
+            fn try_mut_view_ctx(
+                __zordon_buf: &'a mut [u8],
+                __zordon_ctx: &::zordon::error::ParseCtx,
+            ) -> ::core::result::Result<(Self, &'a mut [u8]), ::zordon::error::ViewError> {
+                    #[allow(unused_variables)]
+                    let __zordon_len = __zordon_buf.len();
+                    #(#try_mut_fields)*
+                    #mut_end_align
+
+                    Ok((Self {
+                       #(#field_names,)*
+                    }, __zordon_buf))
+            }
+        }
+
+        impl<'a, 'b> ::zordon::types::Reborrow<'b> for #struct_name<'a> {
+            type Target = #struct_name<'b>;
+        }
+
+        impl<'a> ::zordon::types::TryView<'a> for #struct_name<'a>
+        where
+            #(#ref_bounds,)*
+        {
+            fn try_view_ctx(
+                __zordon_buf: &'a [u8],
+                __zordon_ctx: &::zordon::error::ParseCtx,
+            ) -> ::core::result::Result<(Self, &'a [u8]), ::zordon::error::ViewError> {
+                    #[allow(unused_variables)]
+                    let __zordon_len = __zordon_buf.len();
+                    #(#try_ref_fields)*
+                    #ref_end_align
+
+                    Ok((Self {
+                       #(#field_names,)*
+                    }, __zordon_buf))
+            }
+        }
+    })
+}
+
+fn derive_enum(
+    ast: &DeriveInput,
+    variants: &Punctuated<syn::Variant, syn::Token![,]>,
+) -> syn::Result<TokenStream2> {
+    let enum_name = &ast.ident;
+    let enum_attrs = EnumAttrs::parse(&ast.attrs)?;
+    let attrs = variants
+        .iter()
+        .map(VariantAttrs::parse)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    // Every variant holds a single view, e.g. `Ipv4(Ipv4Header<'a>)`.
+    let types = variants
+        .iter()
+        .map(|v| match &v.fields {
+            syn::Fields::Unnamed(f) if f.unnamed.len() == 1 => Ok(&f.unnamed[0].ty),
+            _ => Err(syn::Error::new_spanned(
+                v,
+                "a MutView variant must hold exactly one view",
+            )),
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    // The variant without a tag takes the bytes of any unknown tag.
+    let mut fallback = None;
+    let mut seen = Vec::new();
+    for (i, (v, a)) in variants.iter().zip(&attrs).enumerate() {
+        if a.tags.is_empty() && fallback.replace(i).is_some() {
+            return Err(syn::Error::new_spanned(
+                v,
+                "only one variant can be left without a tag",
+            ));
+        }
+
+        for tag in &a.tags {
+            let value = tag.base10_parse::<usize>()?;
+            if seen.contains(&value) {
+                return Err(syn::Error::new_spanned(tag, "duplicate tag"));
+            }
+            seen.push(value);
+        }
+    }
+
+    // Tagged variants must implement TryMutView and the fallback TryMutViewVar, as it fills
+    // whatever bytes are left.
+    let bounds = |family: &str| {
+        let view = format_ident!("Try{}View", family);
+        let var = format_ident!("Try{}ViewVar", family);
+
+        types
+            .iter()
+            .enumerate()
+            .map(|(i, ty)| match fallback {
+                Some(f) if f == i => quote! {#ty: ::zordon::types::#var<'a>},
+                _ => quote! {#ty: ::zordon::types::#view<'a>},
+            })
+            .collect::<Vec<_>>()
+    };
+    let mut_bounds = bounds("Mut");
+    let ref_bounds = bounds("");
+
+    // `family` is "Mut" for the TryMutViewTagged impl and "" for the TryViewTagged impl.
+    let tagged_impl = |family: &str, bounds: &[TokenStream2]| {
+        let snake = match family {
+            "" => String::new(),
+            f => format!("{}_", f.to_lowercase()),
+        };
+        let view = format_ident!("Try{}View", family);
+        let var = format_ident!("Try{}ViewVar", family);
+        let tagged = format_ident!("Try{}ViewTagged", family);
+        let ctor = format_ident!("try_{}view_ctx", snake);
+        let len_ctor = format_ident!("try_{}view_len_ctx", snake);
+        let tag_ctor = format_ident!("try_{}view_tag_ctx", snake);
+        let (buf_ty, split) = match family {
+            "" => (quote! {&'a [u8]}, quote! {split_at}),
+            _ => (quote! {&'a mut [u8]}, quote! {split_at_mut}),
+        };
+
+        let arms = variants
+            .iter()
+            .zip(&attrs)
+            .zip(&types)
+            .filter(|((_, a), _)| !a.tags.is_empty())
+            .map(|((v, a), ty)| {
+                let variant = &v.ident;
+                let name_str = variant.to_string();
+                let tags = a
+                    .tags
+                    .iter()
+                    .map(|t| Ok(proc_macro2::Literal::usize_suffixed(t.base10_parse()?)))
+                    .collect::<syn::Result<Vec<_>>>()?;
+
+                Ok(quote! {
+                    #(#tags)|* => {
+                        let __zordon_field_ctx = __zordon_ctx.field(#name_str, 0);
+                        let (v, __zordon_buf) = <#ty as ::zordon::types::#view<'a>>::#ctor(__zordon_buf, &__zordon_field_ctx)?;
+                        (Self::#variant(v), __zordon_buf)
+                    }
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        let fallback_arm = match fallback {
+            Some(i) => {
+                let variant = &variants[i].ident;
+                let name_str = variant.to_string();
+                let ty = types[i];
+
+                quote! {
+                    _ => {
+                        let __zordon_field_ctx = __zordon_ctx.field(#name_str, 0);
+                        let __zordon_byte_len = __zordon_buf.len();
+                        let (v, __zordon_buf) = <#ty as ::zordon::types::#var<'a>>::#len_ctor(__zordon_buf, __zordon_byte_len, &__zordon_field_ctx)?;
+                        (Self::#variant(v), __zordon_buf)
+                    }
+                }
+            }
+            None => quote! {
+                _ => return Err(__zordon_ctx.error(::zordon::error::ViewError::unknown_tag()))
+            },
+        };
+
+        Ok::<_, syn::Error>(quote! {
+            impl<'a> ::zordon::types::#tagged<'a> for #enum_name<'a>
+            where
+                #(#bounds,)*
+            {
+                fn #tag_ctor(
+                    __zordon_buf: #buf_ty,
+                    tag: usize,
+                    len: ::core::option::Option<usize>,
+                    __zordon_ctx: &::zordon::error::ParseCtx,
+                ) -> ::core::result::Result<(Self, #buf_ty), ::zordon::error::ViewError> {
+                    let (__zordon_buf, rest) = match len {
+                        Some(len) if len > __zordon_buf.len() => {
+                            return Err(__zordon_ctx.error(::zordon::error::ViewError::new(len, __zordon_buf.len())))
+                        }
+                        Some(len) => {
+                            let (__zordon_buf, rest) = __zordon_buf.#split(len);
+                            (__zordon_buf, Some(rest))
+                        }
+                        None => (__zordon_buf, None),
+                    };
+
+                    let (v, __zordon_buf) = match tag {
+                        #(#arms)*
+                        #fallback_arm
+                    };
+
+                    Ok((v, rest.unwrap_or(__zordon_buf)))
+                }
+            }
+        })
+    };
+    let mut_tagged = tagged_impl("Mut", &mut_bounds)?;
+    let ref_tagged = tagged_impl("", &ref_bounds)?;
+
+    // Without a tag type the variant can only be chosen by a `tag` attribute on a struct field.
+    let tag_ty = match enum_attrs.tag_type {
+        Some(tag_ty) => tag_ty,
+        None => {
+            return Ok(quote! {
+                #mut_tagged
+                #ref_tagged
+            })
+        }
+    };
+
+    let leading_impl = |family: &str, bounds: &mut Vec<TokenStream2>| {
+        let snake = match family {
+            "" => String::new(),
+            f => format!("{}_", f.to_lowercase()),
+        };
+        let view = format_ident!("Try{}View", family);
+        let tagged = format_ident!("Try{}ViewTagged", family);
+        let ctor = format_ident!("try_{}view_ctx", snake);
+        let tag_ctor = format_ident!("try_{}view_tag_ctx", snake);
+        let buf_ty = match family {
+            "" => quote! {&'a [u8]},
+            _ => quote! {&'a mut [u8]},
+        };
+
+        bounds.push(quote! {#tag_ty: ::zordon::types::#view<'a> + ::zordon::types::LenValue});
+
+        quote! {
+            impl<'a> ::zordon::types::#view<'a> for #enum_name<'a>
+            where
+                #(#bounds,)*
+            {
+                fn #ctor(
+                    __zordon_buf: #buf_ty,
+                    __zordon_ctx: &::zordon::error::ParseCtx,
+                ) -> ::core::result::Result<(Self, #buf_ty), ::zordon::error::ViewError> {
+                    let __zordon_len = __zordon_buf.len();
+                    let __zordon_field_ctx = __zordon_ctx.field("tag", 0);
+                    let (tag, __zordon_buf) = <#tag_ty as ::zordon::types::#view<'a>>::#ctor(__zordon_buf, &__zordon_field_ctx)?;
+                    let tag = ::zordon::types::LenValue::len_value(&tag)
+                        .ok_or_else(|| __zordon_field_ctx.error(::zordon::error::ViewError::unknown_tag()))?;
+                    let __zordon_ctx = __zordon_ctx.advance(__zordon_len - __zordon_buf.len());
+
+                    <Self as ::zordon::types::#tagged<'a>>::#tag_ctor(__zordon_buf, tag, None, &__zordon_ctx)
+                }
+            }
+        }
+    };
+    let mut mut_bounds = mut_bounds;
+    let mut ref_bounds = ref_bounds;
+    let mut_leading = leading_impl("Mut", &mut mut_bounds);
+    let ref_leading = leading_impl("", &mut ref_bounds);
+    let ctors = constructors(enum_name, &mut_bounds, &ref_bounds);
+
+    Ok(quote! {
+        #mut_tagged
+        #ref_tagged
+        #ctors
+        #mut_leading
+        #ref_leading
+
+        impl<'a, 'b> ::zordon::types::Reborrow<'b> for #enum_name<'a> {
+            type Target = #enum_name<'b>;
+        }
+    })
+}
+
+#[proc_macro_derive(EnumRepr)]
+pub fn derive_enum_repr(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let enum_name = &ast.ident;
+    let variants = if let syn::Data::Enum(syn::DataEnum { ref variants, .. }) = ast.data {
+        variants
+    } else {
+        panic!("Macro must be applied to enum")
+    };
+
+    if let Some(v) = variants
+        .iter()
+        .find(|v| !matches!(v.fields, syn::Fields::Unit))
+    {
+        return syn::Error::new_spanned(v, "EnumRepr variants can't hold data")
+            .to_compile_error()
+            .into();
+    }
+
+    // The discriminant type, e.g. the u16 in #[repr(u16)].
+    let repr = ast
+        .attrs
+        .iter()
+        .filter(|a| a.path.is_ident("repr"))
+        .filter_map(|a| {
+            a.parse_args_with(
+                syn::punctuated::Punctuated::<syn::Ident, syn::Token![,]>::parse_terminated,
+            )
+            .ok()
+        })
+        .flatten()
+        .find(|i| i != "C");
+
+    let repr = match repr {
+        Some(repr) => repr,
+        None => {
+            return syn::Error::new_spanned(enum_name, "EnumRepr needs a #[repr(..)] integer type")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    let arms = variants
+        .iter()
+        .map(|v| {
+            let variant = &v.ident;
+
+            quote! {v if v == Self::#variant as #repr => ::core::option::Option::Some(Self::#variant)}
+        })
+        .collect::<Vec<_>>();
+
+    let expanded = quote! {
+        impl ::zordon::types::EnumRepr<#repr> for #enum_name {
+            fn from_repr(v: #repr) -> ::core::option::Option<Self> {
+                match v {
+                    #(#arms,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            fn to_repr(self) -> #repr {
+                self as #repr
+            }
+        }
+    };
+
+    expanded.into()
+}
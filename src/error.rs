@@ -0,0 +1,581 @@
+//! `zordon` errors
+//!
+//! Contains the error returned by the fallible `try_mut_view` constructors, the [`ParseCtx`]
+//! used to attach a field path and absolute offset to it and the [`OutOfRange`] error returned by
+//! fallible setters.
+
+use core::fmt;
+
+use crate::types::Endian;
+
+/// Maximum number of field names a [`FieldPath`] can hold.
+pub const MAX_PATH_DEPTH: usize = 4;
+
+/// Maximum number of bytes a [`FieldValue`] can hold.
+pub const MAX_VALUE_LEN: usize = 8;
+
+/// The reason a view failed to parse.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ViewErrorKind {
+    /// The buffer was shorter than the view, see `needed` and `available`.
+    Truncated,
+    /// A [`DynEnd`](crate::types::DynEnd) view was parsed with no [`Endian`] in the [`ParseCtx`].
+    MissingEndian,
+    /// The field giving a view's length held a value which isn't a valid `usize`, e.g. a negative
+    /// count.
+    InvalidLength,
+    /// A length expression in a derive attribute overflowed, underflowed or divided by zero.
+    LengthOverflow,
+    /// A tagged union was given a tag matching none of its variants and has no fallback variant.
+    UnknownTag,
+    /// A field with a `magic` or `eq` attribute in the derive didn't hold the expected value.
+    UnexpectedValue {
+        /// The value given by the attribute.
+        expected: FieldValue,
+        /// The value held by the field.
+        found: FieldValue,
+    },
+    /// A field marked `reserved` in the derive wasn't zero.
+    ReservedNotZero,
+    /// A field with an `at` attribute in the derive started before the end of the bytes already
+    /// viewed, so its view would alias them. `offset` is where the field would have started.
+    Overlap,
+    /// An [`OffsetView`](crate::types::OffsetView) held an offset outside of its base buffer, or
+    /// one which couldn't be translated. `offset` is the offending offset, truncated to a `usize`
+    /// if it doesn't fit in one.
+    InvalidOffset,
+}
+
+/// Returned by `try_mut_view` when a view can't be parsed, most commonly because the buffer is
+/// too short.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ViewError {
+    /// Why the view failed to parse.
+    pub kind: ViewErrorKind,
+    /// Number of bytes the view needed.
+    pub needed: usize,
+    /// Number of bytes that were left in the buffer.
+    pub available: usize,
+    /// Byte offset in the original buffer where the view would have started.
+    pub offset: usize,
+    /// Path of the field that failed to parse, e.g. `example_b.u16_f` or `entries[3].name`.
+    pub path: FieldPath,
+}
+
+impl ViewError {
+    /// Constructs a new [`ViewErrorKind::Truncated`] error at offset 0 with an empty path.
+    pub fn new(needed: usize, available: usize) -> Self {
+        Self {
+            kind: ViewErrorKind::Truncated,
+            needed,
+            available,
+            offset: 0,
+            path: FieldPath::default(),
+        }
+    }
+
+    /// Constructs a new [`ViewErrorKind::MissingEndian`] error at offset 0 with an empty path.
+    pub fn missing_endian() -> Self {
+        Self {
+            kind: ViewErrorKind::MissingEndian,
+            ..Self::new(0, 0)
+        }
+    }
+
+    /// Constructs a new [`ViewErrorKind::InvalidLength`] error at offset 0 with an empty path.
+    pub fn invalid_length() -> Self {
+        Self {
+            kind: ViewErrorKind::InvalidLength,
+            ..Self::new(0, 0)
+        }
+    }
+
+    /// Constructs a new [`ViewErrorKind::LengthOverflow`] error at offset 0 with an empty path.
+    pub fn length_overflow() -> Self {
+        Self {
+            kind: ViewErrorKind::LengthOverflow,
+            ..Self::new(0, 0)
+        }
+    }
+
+    /// Constructs a new [`ViewErrorKind::UnknownTag`] error at offset 0 with an empty path.
+    pub fn unknown_tag() -> Self {
+        Self {
+            kind: ViewErrorKind::UnknownTag,
+            ..Self::new(0, 0)
+        }
+    }
+
+    /// Constructs a new [`ViewErrorKind::UnexpectedValue`] error at offset 0 with an empty path.
+    pub fn unexpected_value(expected: FieldValue, found: FieldValue) -> Self {
+        Self {
+            kind: ViewErrorKind::UnexpectedValue { expected, found },
+            ..Self::new(0, 0)
+        }
+    }
+
+    /// Constructs a new [`ViewErrorKind::ReservedNotZero`] error at offset 0 with an empty path.
+    pub fn reserved_not_zero() -> Self {
+        Self {
+            kind: ViewErrorKind::ReservedNotZero,
+            ..Self::new(0, 0)
+        }
+    }
+
+    /// Constructs a new [`ViewErrorKind::Overlap`] error at offset 0 with an empty path.
+    pub fn overlap() -> Self {
+        Self {
+            kind: ViewErrorKind::Overlap,
+            ..Self::new(0, 0)
+        }
+    }
+
+    /// Constructs a new [`ViewErrorKind::InvalidOffset`] error at offset 0 with an empty path.
+    pub fn invalid_offset() -> Self {
+        Self {
+            kind: ViewErrorKind::InvalidOffset,
+            ..Self::new(0, 0)
+        }
+    }
+
+    /// Returns the error with `offset` added to its current offset.
+    ///
+    /// Used when a view is parsed from a sub-slice of a larger buffer.
+    pub fn offset_by(self, offset: usize) -> Self {
+        Self {
+            offset: self.offset.saturating_add(offset),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for ViewError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ViewErrorKind::Truncated => {
+                write!(f, "needed {} bytes at offset {:#x}", self.needed, self.offset)?
+            }
+            ViewErrorKind::MissingEndian => {
+                write!(f, "no byte order given for view at offset {:#x}", self.offset)?
+            }
+            ViewErrorKind::InvalidLength => {
+                write!(f, "invalid length for view at offset {:#x}", self.offset)?
+            }
+            ViewErrorKind::LengthOverflow => {
+                write!(f, "length overflowed for view at offset {:#x}", self.offset)?
+            }
+            ViewErrorKind::UnknownTag => {
+                write!(f, "unknown tag for view at offset {:#x}", self.offset)?
+            }
+            ViewErrorKind::UnexpectedValue { found, .. } => write!(
+                f,
+                "unexpected value {} for view at offset {:#x}",
+                found, self.offset
+            )?,
+            ViewErrorKind::ReservedNotZero => {
+                write!(f, "reserved bits not zero for view at offset {:#x}", self.offset)?
+            }
+            ViewErrorKind::Overlap => {
+                write!(f, "overlapping view at offset {:#x}", self.offset)?
+            }
+            ViewErrorKind::InvalidOffset => {
+                write!(f, "invalid offset {:#x} for view", self.offset)?
+            }
+        }
+
+        if !self.path.is_empty() {
+            write!(f, " ({})", self.path)?;
+        }
+
+        match self.kind {
+            ViewErrorKind::Truncated => write!(f, " but only {} available", self.available),
+            ViewErrorKind::UnexpectedValue { expected, .. } => write!(f, ", expected {}", expected),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ValueRepr {
+    Unsigned,
+    Signed,
+    Float,
+    Bytes,
+}
+
+/// A value held by, or expected of, a field with a `magic` or `eq` attribute in the derive.
+///
+/// The value is stored inline so [`ViewError`] stays `Copy` and small. Integers wider than 64
+/// bits keep their low 64 bits and byte strings their first [`MAX_VALUE_LEN`] bytes, and are
+/// displayed with a `...` where the rest was dropped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldValue {
+    repr: ValueRepr,
+    len: u8,
+    truncated: bool,
+    bytes: [u8; MAX_VALUE_LEN],
+}
+
+impl FieldValue {
+    fn int(repr: ValueRepr, v: u64, truncated: bool) -> Self {
+        Self {
+            repr,
+            len: MAX_VALUE_LEN as u8,
+            truncated,
+            bytes: v.to_le_bytes(),
+        }
+    }
+
+    fn unsigned(v: u128) -> Self {
+        Self::int(ValueRepr::Unsigned, v as u64, v > u128::from(u64::MAX))
+    }
+
+    fn signed(v: i128) -> Self {
+        let truncated = v < i128::from(i64::MIN) || v > i128::from(i64::MAX);
+        Self::int(ValueRepr::Signed, v as u64, truncated)
+    }
+
+    fn word(&self) -> u64 {
+        u64::from_le_bytes(self.bytes)
+    }
+
+    /// Returns true if part of the value was dropped because it was too wide.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns the value if it is an integer which wasn't truncated.
+    pub fn as_i128(&self) -> Option<i128> {
+        match self.repr {
+            _ if self.truncated => None,
+            ValueRepr::Unsigned => Some(i128::from(self.word())),
+            ValueRepr::Signed => Some(i128::from(self.word() as i64)),
+            ValueRepr::Float | ValueRepr::Bytes => None,
+        }
+    }
+
+    /// Returns the held bytes if the value is a byte string.
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self.repr {
+            ValueRepr::Bytes => Some(&self.bytes[..self.len as usize]),
+            _ => None,
+        }
+    }
+}
+
+/// Template for implementing From<_> for FieldValue on integers.
+macro_rules! impl_field_value_int {
+    ($ctor:ident, $wide:ident, $($type:ident),*) => {
+        $(
+            impl From<$type> for FieldValue {
+                fn from(v: $type) -> Self {
+                    Self::$ctor($wide::from(v))
+                }
+            }
+        )*
+    };
+}
+
+impl_field_value_int!(unsigned, u128, u8, u16, u32, u64);
+impl_field_value_int!(signed, i128, i8, i16, i32, i64);
+
+impl From<u128> for FieldValue {
+    fn from(v: u128) -> Self {
+        Self::unsigned(v)
+    }
+}
+
+impl From<i128> for FieldValue {
+    fn from(v: i128) -> Self {
+        Self::signed(v)
+    }
+}
+
+impl From<f32> for FieldValue {
+    fn from(v: f32) -> Self {
+        Self::from(f64::from(v))
+    }
+}
+
+impl From<f64> for FieldValue {
+    fn from(v: f64) -> Self {
+        Self::int(ValueRepr::Float, v.to_bits(), false)
+    }
+}
+
+impl<const L: usize> From<[u8; L]> for FieldValue {
+    fn from(v: [u8; L]) -> Self {
+        let len = L.min(MAX_VALUE_LEN);
+        let mut bytes = [0; MAX_VALUE_LEN];
+        bytes[..len].copy_from_slice(&v[..len]);
+
+        Self {
+            repr: ValueRepr::Bytes,
+            len: len as u8,
+            truncated: L > MAX_VALUE_LEN,
+            bytes,
+        }
+    }
+}
+
+impl fmt::Display for FieldValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.repr {
+            _ if self.truncated && self.repr != ValueRepr::Bytes => {
+                write!(f, "0x...{:016x}", self.word())
+            }
+            ValueRepr::Unsigned => write!(f, "{:#x}", self.word()),
+            ValueRepr::Signed => write!(f, "{}", self.word() as i64),
+            ValueRepr::Float => write!(f, "{}", f64::from_bits(self.word())),
+            ValueRepr::Bytes => {
+                f.write_str("b\"")?;
+                for b in &self.bytes[..self.len as usize] {
+                    for c in core::ascii::escape_default(*b) {
+                        write!(f, "{}", c as char)?;
+                    }
+                }
+                if self.truncated {
+                    f.write_str("...")?;
+                }
+                f.write_str("\"")
+            }
+        }
+    }
+}
+
+/// One step of a [`FieldPath`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathSegment {
+    /// A named struct field.
+    Field(&'static str),
+    /// An element of an array of views.
+    Index(usize),
+}
+
+impl PartialEq<&str> for PathSegment {
+    fn eq(&self, other: &&str) -> bool {
+        matches!(self, PathSegment::Field(field) if field == other)
+    }
+}
+
+/// A dotted path of field names and array indices, e.g. `example_b.u16_f` or `entries[3].name`.
+///
+/// The path is stored inline so no allocation is needed. Paths deeper than [`MAX_PATH_DEPTH`]
+/// keep their innermost segments and are displayed with a leading `...`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldPath {
+    segments: [PathSegment; MAX_PATH_DEPTH],
+    len: u8,
+    truncated: bool,
+}
+
+impl Default for FieldPath {
+    fn default() -> Self {
+        Self {
+            segments: [PathSegment::Field(""); MAX_PATH_DEPTH],
+            len: 0,
+            truncated: false,
+        }
+    }
+}
+
+impl FieldPath {
+    /// Returns true if the path holds no segments.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if outer segments were dropped because the path was too deep.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Returns an iterator over the segments, outermost first.
+    pub fn iter(&self) -> impl Iterator<Item = PathSegment> + '_ {
+        self.segments[..self.len as usize].iter().copied()
+    }
+
+    /// Adds `segment` as the new outermost segment.
+    fn push_front(&mut self, segment: PathSegment) {
+        let len = self.len as usize;
+        if len == MAX_PATH_DEPTH {
+            self.truncated = true;
+            return;
+        }
+
+        self.segments.copy_within(0..len, 1);
+        self.segments[0] = segment;
+        self.len += 1;
+    }
+}
+
+impl fmt::Display for FieldPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.truncated {
+            f.write_str("...")?;
+        }
+
+        for (i, segment) in self.iter().enumerate() {
+            match segment {
+                PathSegment::Field(field) => {
+                    if i > 0 {
+                        f.write_str(".")?;
+                    }
+                    f.write_str(field)?;
+                }
+                PathSegment::Index(index) => write!(f, "[{}]", index)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Tracks the field path and absolute offset of the view currently being parsed.
+///
+/// The [`MutView`](crate::MutView) derive threads a [`ParseCtx`] through nested structs via
+/// `try_mut_view_ctx`, so that a [`ViewError`] names the full path of the failing field and its
+/// offset in the original buffer. It also carries the runtime [`Endian`] used by
+/// [`DynEnd`](crate::types::DynEnd) views and whether `reserved` fields are checked.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ParseCtx<'p> {
+    parent: Option<&'p ParseCtx<'p>>,
+    segment: Option<PathSegment>,
+    offset: usize,
+    endian: Option<Endian>,
+    ignore_reserved: bool,
+}
+
+impl<'p> ParseCtx<'p> {
+    /// Constructs a root context whose view starts at `offset` in the original buffer.
+    pub fn new(offset: usize) -> Self {
+        Self {
+            parent: None,
+            segment: None,
+            offset,
+            endian: None,
+            ignore_reserved: false,
+        }
+    }
+
+    /// Returns the context with `endian` as the byte order for any nested `DynEnd` views.
+    pub fn with_endian(self, endian: Endian) -> Self {
+        Self {
+            endian: Some(endian),
+            ..self
+        }
+    }
+
+    /// Returns the context with the checks of fields marked `reserved` turned off, so that
+    /// non-conformant input can still be viewed and then zeroed with
+    /// [`Sanitize`](crate::types::Sanitize).
+    pub fn ignore_reserved(self) -> Self {
+        Self {
+            ignore_reserved: true,
+            ..self
+        }
+    }
+
+    /// Returns a context for the field `name`, starting `rel_offset` bytes after this context.
+    pub fn field(&'p self, name: &'static str, rel_offset: usize) -> ParseCtx<'p> {
+        ParseCtx {
+            parent: Some(self),
+            segment: Some(PathSegment::Field(name)),
+            offset: self.offset.saturating_add(rel_offset),
+            endian: self.endian,
+            ignore_reserved: self.ignore_reserved,
+        }
+    }
+
+    /// Returns a context for element `index` of an array, starting `rel_offset` bytes after this
+    /// context.
+    pub fn index(&'p self, index: usize, rel_offset: usize) -> ParseCtx<'p> {
+        ParseCtx {
+            parent: Some(self),
+            segment: Some(PathSegment::Index(index)),
+            offset: self.offset.saturating_add(rel_offset),
+            endian: self.endian,
+            ignore_reserved: self.ignore_reserved,
+        }
+    }
+
+    /// Returns the context moved `rel_offset` bytes further into the same field, e.g. past the tag
+    /// preceding a tagged union's variant.
+    pub fn advance(self, rel_offset: usize) -> Self {
+        Self {
+            offset: self.offset.saturating_add(rel_offset),
+            ..self
+        }
+    }
+
+    /// Returns the absolute offset of this context in the original buffer.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the byte order for `DynEnd` views, if one was given.
+    pub fn endian(&self) -> Option<Endian> {
+        self.endian
+    }
+
+    /// Returns true if fields marked `reserved` aren't checked.
+    pub fn ignores_reserved(&self) -> bool {
+        self.ignore_reserved
+    }
+
+    /// Returns a root context at the same offset with the same byte order and reserved checks,
+    /// which can be kept after the parent contexts are gone.
+    pub(crate) fn detach(&self) -> ParseCtx<'static> {
+        ParseCtx {
+            parent: None,
+            segment: None,
+            offset: self.offset,
+            endian: self.endian,
+            ignore_reserved: self.ignore_reserved,
+        }
+    }
+
+    /// Returns the field path leading to this context.
+    pub fn path(&self) -> FieldPath {
+        let mut path = FieldPath::default();
+        let mut ctx = Some(self);
+
+        while let Some(c) = ctx {
+            if let Some(segment) = c.segment {
+                path.push_front(segment);
+            }
+            ctx = c.parent;
+        }
+
+        path
+    }
+
+    /// Attaches this context's path and offset to an error returned by a leaf view.
+    pub fn error(&self, e: ViewError) -> ViewError {
+        ViewError {
+            path: self.path(),
+            ..e.offset_by(self.offset)
+        }
+    }
+}
+
+/// Returned by fallible setters such as `try_set` when a value does not fit in the view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfRange {
+    /// The rejected value.
+    pub value: i128,
+    /// Smallest value the view can hold.
+    pub min: i128,
+    /// Largest value the view can hold.
+    pub max: i128,
+}
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "value {} is outside of the range {}..={}",
+            self.value, self.min, self.max
+        )
+    }
+}
@@ -8,7 +8,8 @@
 //!
 //! # About
 //! 
-//! `zordon` provides simple low-level abstractions for zero-copy parsing and mutation. It is a no_std crate with alloc.
+//! `zordon` provides simple low-level abstractions for zero-copy parsing and mutation. It is a no_std crate,
+//! and only needs alloc for the reference counted shared views enabled by the default `alloc` feature.
 //!
 //! `zordon` types allow a single mutable u8 buffer to be treated as
 //! a series of u8-u128, i8-i128 or [u8; _] values without the need to copy data in the original buffer. Setting, getting and
@@ -42,6 +43,7 @@
 //! [`MulByteView`]: types::MulByteView
 //! [`ArrayView`]: types::ArrayView
 //! [`VarArrayView`]: types::VarArrayView
+//! [`SharedArrayView`]: types::SharedArrayView
 //! [`ModByteView`]: types::ModByteView
 //! [`ModMulByteView`]: types::ModMulByteView
 //!
@@ -59,9 +61,13 @@
 //!
 //! - `ByteView<'a, u8>` specifies that the underlying data is a single byte value of type [`u8`]
 //! - `MulByteView<'a, u16, LitEnd>` specifies that the underlying data is a little endian two byte
-//! value of type [`u16`].
+//!   value of type [`u16`].
 //!     - `LitEnd` can be swapped with `BigEnd` and the data will be treated as big endian.
-//!     - `u16` can be swapped with u32-u128 or i16-i128
+//!       `NatEnd` uses the host's byte order and `MidEnd` a PDP-11 style word swapped order.
+//!     - `u16` can be swapped with u32-u128, i16-i128, f32 or f64
+//!     - `F16` (binary16) and `BF16` (bfloat16) are also supported, with `val`/`set` converting to and from `f32`
+//!     - `U24`, `U40`, `U48`, `U56` and their signed `I*` counterparts are also supported, with `val`/`set`
+//!       using the next largest primitive. `set` masks values which don't fit, `try_set` rejects them
 //! - `ArrayView<'a, [u8; 3]>` specifies that the underlying data is three byte value of type [`u8; 3`]
 //!
 //! #### Instantiating the struct
@@ -115,7 +121,9 @@
 //!
 //! ##### [`ArrayView`]
 //! Retriving the underlying value for [`ArrayView`] works slightly differently. Rather than returning
-//! the data, a mutable/immutable reference to the data is returned.
+//! the data, a mutable/immutable reference to the data is returned. [`ArrayView`] holds its slice
+//! directly, so no allocation or runtime borrow checking is involved. If the view needs to be aliased,
+//! `into_shared` converts it into a reference counted [`SharedArrayView`].
 //! ```
 //! # use zordon::prelude::*;
 //! #[derive(MutView)]
@@ -137,7 +145,7 @@
 //!     
 //!     // as mutable reference
 //!     {
-//!         let mut m_ref = example.arr_f.as_mut_ref();
+//!         let m_ref: &mut [u8; 3] = example.arr_f.as_mut_ref();
 //!         m_ref[0] = 0xFF;
 //!     }    
 //!     assert_eq!(input_buf, [0xFF, 0xBB, 0xCC]);       
@@ -176,6 +184,58 @@
 //!   assert_eq!(input_buf[0], 0xFF);
 //! }
 //! ```
+//! ## Fallible parsing
+//! `mut_view` panics if the buffer is shorter than the view. When parsing untrusted input use
+//! `try_mut_view` instead, which is implemented on every `zordon` type and by the [`MutView`] derive.
+//! On failure it returns a [`ViewError`] holding the number of bytes needed, the number available,
+//! the byte offset where parsing failed and the path of the field that failed.
+//! ```
+//! use zordon::prelude::*;
+//!
+//! #[derive(MutView)]
+//! struct ExampleA<'a> {
+//!     u8_f: ByteView<'a, u8>,
+//! }
+//!
+//! #[derive(MutView)]
+//! struct ExampleB<'a> {
+//!     u16_f: MulByteView<'a, u16, BigEnd>,
+//! }
+//!
+//! #[derive(MutView)]
+//! struct Composite<'a> {
+//!     example_a: ExampleA<'a>,
+//!     example_b: ExampleB<'a>,
+//! }
+//!
+//! fn main() {
+//!     let mut input_buf = [0x00, 0x01];
+//!     let err = Composite::try_mut_view(&mut input_buf).err().unwrap();
+//!
+//!     assert_eq!((err.needed, err.available, err.offset), (2, 1, 1));
+//!     assert_eq!(
+//!         err.to_string(),
+//!         "needed 2 bytes at offset 0x1 (example_b.u16_f) but only 1 available"
+//!     );
+//! }
+//! ```
+//! Nested derives thread a [`ParseCtx`] through `try_mut_view_ctx`, which is how the path and the
+//! absolute offset are tracked. A sub-slice of a larger buffer can be parsed with its real offset
+//! by passing `&ParseCtx::new(offset)`.
+//!
+//! [`ViewError`]: error::ViewError
+//! [`ParseCtx`]: error::ParseCtx
+//!
+//! ### Migrating from 0.2
+//! The [`MutView`] derive now builds every field through the [`TryMutView`] trait, and the
+//! generated `mut_view` is `try_mut_view` followed by a panic on error. A struct holding a hand
+//! written view type that only has a `mut_view` method no longer compiles; implement
+//! [`TryMutView`] for that type, returning `ctx.error(ViewError::new(needed, buf.len()))` when the
+//! buffer is too short, and [`TryView`] as well if the struct's `view` constructors are used.
+//!
+//! [`TryMutView`]: types::TryMutView
+//! [`TryView`]: types::TryView
+//!
 //! ## [`VarArrayView`] example
 //! This type creates a mutable array view which for a non compile time known length.
 //! ```
@@ -189,6 +249,570 @@
 //!    assert_eq!(*t.as_ref(), buf);
 //! }
 //! ```
+//! ### Lengths from earlier fields
+//! In a derived struct, the length of a [`VarArrayView`] (or any other runtime length view) is given by
+//! a `#[zordon(count = "field")]` attribute naming an earlier field holding the number of elements, or
+//! `#[zordon(len = "field")]` for the number of bytes. An integer literal can be used instead of a
+//! field name. A negative or too large length fails with [`ViewErrorKind::InvalidLength`].
+//!
+//! The string can also be an arithmetic expression over earlier fields and integers using `+`, `-`,
+//! `*`, `/`, `%`, `<<`, `>>`, `&`, `|` and parentheses, e.g. `len = "header_len * 4 - 20"`. The
+//! arithmetic is checked, so an overflow, underflow, division by zero or a shift that drops set bits
+//! fails with [`ViewErrorKind::LengthOverflow`] rather than panicking. Any other name, such as `HEADER_LEN` or `consts::HEADER_LEN`, is read as a
+//! constant in scope, while naming the field itself or a later field is a compile error.
+//! ```
+//! use zordon::prelude::*;
+//!
+//! #[derive(MutView)]
+//! struct Tlv<'a> {
+//!     kind: ByteView<'a, u8>,
+//!     len: ByteView<'a, u8>,
+//!     #[zordon(len = "len")]
+//!     value: VarArrayView<'a, u8>,
+//! }
+//!
+//! #[derive(MutView)]
+//! struct Chunk<'a> {
+//!     size: MulByteView<'a, u32, LitEnd>,
+//!     #[zordon(len = "size - 4")]
+//!     data: VarArrayView<'a, u8>,
+//! }
+//!
+//! fn main() {
+//!     let mut input_buf = [0x01, 0x02, 0xAA, 0xBB, 0x02, 0x00];
+//!     let (tlv, rest) = Tlv::mut_view(&mut input_buf);
+//!
+//!     assert_eq!(tlv.value.as_ref(), [0xAA, 0xBB]);
+//!     assert_eq!(rest.len(), 2);
+//!
+//!     let mut input_buf = [0x06, 0x00, 0x00, 0x00, 0xAA, 0xBB];
+//!     let (chunk, _) = Chunk::mut_view(&mut input_buf);
+//!     assert_eq!(chunk.data.as_ref(), [0xAA, 0xBB]);
+//!
+//!     let mut input_buf = [0x02, 0x00, 0x00, 0x00];
+//!     let err = Chunk::try_mut_view(&mut input_buf).err().unwrap();
+//!     assert_eq!(err.kind, ViewErrorKind::LengthOverflow);
+//! }
+//! ```
+//! [`ViewErrorKind::InvalidLength`]: error::ViewErrorKind::InvalidLength
+//! [`ViewErrorKind::LengthOverflow`]: error::ViewErrorKind::LengthOverflow
+//!
+//! ## Conditional fields
+//! An `Option` field with a `#[zordon(if = "...")]` attribute is only parsed when its predicate holds,
+//! otherwise it is `None` and its bytes are left for the next field. Predicates use the same
+//! expressions as lengths, plus comparisons, `&&`, `||`, `!` and the bitwise operators, e.g.
+//! `if = "flags & 0x1 != 0"`. An `Option` field without an `if` attribute is always `None`.
+//! ```
+//! use zordon::prelude::*;
+//!
+//! #[derive(MutView)]
+//! struct Header<'a> {
+//!     version: ByteView<'a, u8>,
+//!     #[zordon(if = "version >= 2")]
+//!     checksum: Option<MulByteView<'a, u16, BigEnd>>,
+//!     kind: ByteView<'a, u8>,
+//! }
+//!
+//! fn main() {
+//!     let mut input_buf = [0x01, 0x05];
+//!     let (header, _) = Header::mut_view(&mut input_buf);
+//!     assert!(header.checksum.is_none());
+//!     assert_eq!(header.kind.val(), 0x05);
+//!
+//!     let mut input_buf = [0x02, 0x12, 0x34, 0x05];
+//!     let (header, _) = Header::mut_view(&mut input_buf);
+//!     assert_eq!(header.checksum.unwrap().val(), 0x1234);
+//!     assert_eq!(header.kind.val(), 0x05);
+//! }
+//! ```
+//!
+//! ## Magic numbers
+//! `#[zordon(magic = b"...")]` on an [`ArrayView`] field and `#[zordon(eq = ...)]` on an integer field
+//! check the field once it is parsed, so `try_mut_view` fails with [`ViewErrorKind::UnexpectedValue`]
+//! rather than treating garbage as a valid struct. The error names the field, its offset and the
+//! expected and found values.
+//! ```
+//! use zordon::prelude::*;
+//!
+//! #[derive(MutView)]
+//! struct DosHeader<'a> {
+//!     #[zordon(magic = b"MZ")]
+//!     magic: ArrayView<'a, [u8; 2]>,
+//!     #[zordon(eq = 0x4550)]
+//!     signature: MulByteView<'a, u16, BigEnd>,
+//! }
+//!
+//! fn main() {
+//!     let mut input_buf = [b'M', b'Z', 0x45, 0x50];
+//!     assert!(DosHeader::try_mut_view(&mut input_buf).is_ok());
+//!
+//!     let mut input_buf = [b'P', b'K', 0x03, 0x04];
+//!     let err = DosHeader::try_mut_view(&mut input_buf).err().unwrap();
+//!     assert_eq!(
+//!         err.to_string(),
+//!         "unexpected value b\"PK\" for view at offset 0x0 (magic), expected b\"MZ\""
+//!     );
+//! }
+//! ```
+//! [`ViewErrorKind::UnexpectedValue`]: error::ViewErrorKind::UnexpectedValue
+//!
+//! ## Reserved fields
+//! A field marked `#[zordon(reserved)]` must be zero, otherwise `try_mut_view` fails with
+//! [`ViewErrorKind::ReservedNotZero`]. It can be a byte, multi byte or array view, or a [`BitView`]
+//! for reserved bits within a word. Parsing with [`ParseCtx::ignore_reserved`] skips the check, and
+//! the derived [`Sanitize`] impl zeroes every reserved field.
+//! ```
+//! use zordon::prelude::*;
+//!
+//! #[derive(MutView)]
+//! struct Header<'a> {
+//!     kind: ByteView<'a, u8>,
+//!     #[zordon(reserved)]
+//!     reserved: ArrayView<'a, [u8; 3]>,
+//! }
+//!
+//! fn main() {
+//!     let mut input_buf = [0x01, 0x00, 0x7F, 0x00];
+//!     assert!(Header::try_mut_view(&mut input_buf).is_err());
+//!
+//!     let ctx = ParseCtx::default().ignore_reserved();
+//!     let (mut header, _) = Header::try_mut_view_ctx(&mut input_buf, &ctx).unwrap();
+//!     header.sanitize();
+//!     assert_eq!(input_buf, [0x01, 0x00, 0x00, 0x00]);
+//! }
+//! ```
+//! [`ViewErrorKind::ReservedNotZero`]: error::ViewErrorKind::ReservedNotZero
+//! [`ParseCtx::ignore_reserved`]: error::ParseCtx::ignore_reserved
+//! [`Sanitize`]: types::Sanitize
+//!
+//! ## Padding and alignment
+//! `#[zordon(pad = N)]` skips N bytes before a field, where N can also be a length expression, and
+//! `#[zordon(align = N)]` skips bytes until the field's offset from the start of the struct is a
+//! multiple of N. `abs_align` aligns relative to the start of the buffer instead, as given by the
+//! [`ParseCtx`] offset. Padding bytes aren't fields. On the struct itself, `align` and `abs_align` pad
+//! the end of the struct, e.g. for the 2 byte alignment of RIFF chunks.
+//! ```
+//! use zordon::prelude::*;
+//!
+//! #[derive(MutView)]
+//! #[zordon(align = 2)]
+//! struct Chunk<'a> {
+//!     id: ArrayView<'a, [u8; 4]>,
+//!     size: MulByteView<'a, u32, LitEnd>,
+//!     #[zordon(len = "size")]
+//!     data: VarArrayView<'a, u8>,
+//! }
+//!
+//! fn main() {
+//!     let mut input_buf = *b"abcd\x01\x00\x00\x00X\0efgh\x00\x00\x00\x00";
+//!     let (chunk, leftover) = Chunk::mut_view(&mut input_buf);
+//!     assert_eq!(chunk.data.as_ref(), b"X");
+//!
+//!     let (chunk, _) = Chunk::mut_view(leftover);
+//!     assert_eq!(chunk.id.as_ref(), b"efgh");
+//! }
+//! ```
+//!
+//! ## Fields at an offset
+//! Formats such as PE and ELF point to structures by offset instead of placing them next to each
+//! other. `#[zordon(at = "...")]` seeks to an offset from the start of the struct, given by a length
+//! expression, before parsing the field, and `abs_at` seeks to an offset from the start of the buffer
+//! as given by the [`ParseCtx`] offset. The skipped bytes aren't fields and any later fields follow on
+//! from the seeked field.
+//!
+//! Views never alias, so an offset behind the bytes already viewed fails with
+//! [`ViewErrorKind::Overlap`]. Several fields at offsets must therefore be declared in increasing
+//! offset order.
+//! ```
+//! use zordon::prelude::*;
+//!
+//! #[derive(MutView)]
+//! struct PeHeader<'a> {
+//!     signature: ArrayView<'a, [u8; 4]>,
+//! }
+//!
+//! #[derive(MutView)]
+//! struct DosHeader<'a> {
+//!     #[zordon(magic = b"MZ")]
+//!     magic: ArrayView<'a, [u8; 2]>,
+//!     e_lfanew: MulByteView<'a, u32, LitEnd>,
+//!     #[zordon(at = "e_lfanew")]
+//!     pe: PeHeader<'a>,
+//! }
+//!
+//! fn main() {
+//!     let mut input_buf = *b"MZ\x08\x00\x00\x00..PE\0\0";
+//!     let (dos, _) = DosHeader::mut_view(&mut input_buf);
+//!     assert_eq!(dos.pe.signature.as_ref(), b"PE\0\0");
+//!
+//!     let mut input_buf = *b"MZ\x02\x00\x00\x00..PE\0\0";
+//!     let err = DosHeader::try_mut_view(&mut input_buf).err().unwrap();
+//!     assert_eq!(err.kind, ViewErrorKind::Overlap);
+//! }
+//! ```
+//! [`ViewErrorKind::Overlap`]: error::ViewErrorKind::Overlap
+//!
+//! ## Following offsets
+//! When a target can't be parsed in order, e.g. an RVA which must first be translated through a PE
+//! section table, an [`OffsetView<'a, T, E, O>`] field holds the offset as O (u32 by default) with
+//! byte order E and views the T it points to on demand. `try_follow` views the target in a base
+//! buffer, `try_follow_ctx` takes a [`ParseCtx`] giving where the base starts, and `try_follow_map`
+//! translates the offset first. Setting the offset retargets the view in place. [`OffsetRef`] is the
+//! read-only counterpart.
+//! ```
+//! use zordon::prelude::*;
+//!
+//! #[derive(MutView)]
+//! struct Section<'a> {
+//!     kind: ByteView<'a, u8>,
+//! }
+//!
+//! #[derive(MutView)]
+//! struct Header<'a> {
+//!     section: OffsetView<'a, Section<'a>, LitEnd>,
+//! }
+//!
+//! fn main() {
+//!     let mut input_buf = [0x05, 0x00, 0x00, 0x00, 0xAA, 0xBB];
+//!     let (mut header, leftover) = Header::mut_view(&mut input_buf);
+//!
+//!     // The leftover starts 4 bytes into the buffer the offset points into.
+//!     let ctx = ParseCtx::new(4);
+//!     let section = header.section.try_follow_ctx(leftover, &ctx).unwrap();
+//!     assert_eq!(section.kind.val(), 0xBB);
+//!
+//!     header.section.try_set(4).unwrap();
+//!     let section = header.section.try_follow_ctx(leftover, &ctx).unwrap();
+//!     assert_eq!(section.kind.val(), 0xAA);
+//!     assert_eq!(input_buf[..4], [0x04, 0x00, 0x00, 0x00]);
+//! }
+//! ```
+//! [`OffsetView<'a, T, E, O>`]: types::OffsetView
+//! [`OffsetRef`]: types::OffsetRef
+//!
+//! ## Layout constants
+//! The [`MutView`] derive emits an `OFFSET_<FIELD>` constant for every field whose offset is known at
+//! compile time, which is every field before the first one with a runtime length, an `if`, an `at` or
+//! a pad from an expression. When every field's length and position is known it also emits a `SIZE`
+//! constant and implements [`FixedSize`], so the struct can be nested in other layouts. Each offset
+//! only requires the fields before it to implement [`FixedSize`].
+//! ```
+//! use zordon::prelude::*;
+//!
+//! #[derive(MutView)]
+//! struct Header<'a> {
+//!     kind: ByteView<'a, u8>,
+//!     #[zordon(align = 4)]
+//!     len: MulByteView<'a, u32, BigEnd>,
+//!     id: ArrayView<'a, [u8; 8]>,
+//! }
+//!
+//! const _: () = assert!(Header::SIZE == 16);
+//!
+//! fn main() {
+//!     assert_eq!(Header::OFFSET_LEN, 4);
+//!     assert_eq!(Header::OFFSET_ID, 8);
+//!
+//!     let input_buf = [0u8; 12];
+//!     assert!(input_buf.len() < Header::SIZE);
+//! }
+//! ```
+//! [`FixedSize`]: types::FixedSize
+//!
+//! ## Runtime byte order
+//! Formats such as TIFF, pcap and ELF declare their byte order in a header. Using `DynEnd` as the E
+//! in `MulByteView<'a, T, E>` defers the choice to view time. The [`MutView`] derive implements
+//! `mut_view_endian`/`try_mut_view_endian` which thread the given [`Endian`] into every `DynEnd`
+//! field, including those of nested structs. Parsing a struct holding `DynEnd` fields with plain
+//! `try_mut_view` fails with [`ViewErrorKind::MissingEndian`].
+//! ```
+//! use zordon::prelude::*;
+//!
+//! #[derive(MutView)]
+//! struct Example<'a> {
+//!     u16_f: MulByteView<'a, u16, DynEnd>,
+//!     u32_f: MulByteView<'a, u32, DynEnd>,
+//! }
+//!
+//! fn main() {
+//!     let mut input_buf = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05];
+//!     let endian = if input_buf[0] == 0 { Endian::Big } else { Endian::Little };
+//!     let (example, _) = Example::mut_view_endian(&mut input_buf, endian);
+//!
+//!     assert_eq!(example.u16_f.val(), 0x0001);
+//!     assert_eq!(example.u32_f.val(), 0x02030405);
+//! }
+//! ```
+//! [`Endian`]: types::Endian
+//! [`ViewErrorKind::MissingEndian`]: error::ViewErrorKind::MissingEndian
+//!
+//! ## Read-only views
+//! When only shared access to the data is available (e.g. `include_bytes!` blobs or slices shared
+//! across threads) the read-only counterparts [`ByteRef`], [`MulByteRef`], [`ArrayRef`] and
+//! [`VarArrayRef`] can be used. They provide `val()`/`as_ref()` but no way to set the data. For a struct
+//! made of read-only views the [`MutView`] derive implements `view`/`try_view` taking a `&[u8]`.
+//! ```
+//! use zordon::prelude::*;
+//!
+//! #[derive(MutView)]
+//! struct Example<'a> {
+//!     u8_f: ByteRef<'a, u8>,
+//!     u16_f: MulByteRef<'a, u16, BigEnd>,
+//!     arr_f: ArrayRef<'a, [u8; 3]>,
+//! }
+//!
+//! static INPUT: [u8; 6] = [0x00, 0x01, 0x02, 0x03, 0x04, 0x05];
+//!
+//! fn main() {
+//!     let (example, _) = Example::view(&INPUT);
+//!
+//!     assert_eq!(example.u8_f.val(), 0x00);
+//!     assert_eq!(example.u16_f.val(), 0x0102);
+//!     assert_eq!(*example.arr_f.as_ref(), [0x03, 0x04, 0x05]);
+//! }
+//! ```
+//! [`ByteRef`]: types::ByteRef
+//! [`MulByteRef`]: types::MulByteRef
+//! [`ArrayRef`]: types::ArrayRef
+//! [`VarArrayRef`]: types::VarArrayRef
+//!
+//! ## Multi byte arrays
+//! Tables of multi byte values, such as PE data directories or audio samples, are viewed with
+//! [`MulArrayView`] for a fixed length or [`VarMulArrayView`] for a length only known at runtime.
+//! Elements are read and written in the array's byte order with `get`/`set`, iterated with
+//! `iter`/`iter_mut` and bulk copied to and from primitive slices with `copy_to`/`copy_from`.
+//! [`MulArrayRef`] and [`VarMulArrayRef`] are the read-only counterparts.
+//! ```
+//! use zordon::prelude::*;
+//!
+//! #[derive(MutView)]
+//! struct Example<'a> {
+//!     offsets: MulArrayView<'a, [u32; 2], BigEnd>,
+//! }
+//!
+//! fn main() {
+//!     let mut input_buf = [0x00, 0x00, 0x00, 0x10, 0x00, 0x00, 0x00, 0x20];
+//!     let (mut example, _) = Example::mut_view(&mut input_buf);
+//!
+//!     assert_eq!(example.offsets.get(1), Some(0x20));
+//!     example.offsets.set(0, 0x30);
+//!
+//!     let mut offsets = [0; 2];
+//!     example.offsets.copy_to(&mut offsets);
+//!     assert_eq!(offsets, [0x30, 0x20]);
+//! }
+//! ```
+//! [`MulArrayView`]: types::MulArrayView
+//! [`VarMulArrayView`]: types::VarMulArrayView
+//! [`MulArrayRef`]: types::MulArrayRef
+//! [`VarMulArrayRef`]: types::VarMulArrayRef
+//!
+//! ## Arrays of views
+//! [`ViewArray`] parses N views one after another, including structs using the [`MutView`] derive,
+//! and derefs to a slice of them. [`VarViewArray`] does the same for a count only known at runtime and
+//! needs the `alloc` feature. [`LazyViewArray`] and [`LazyViewArrayRef`] hold a runtime count
+//! without allocating, viewing each element again as it is iterated. Errors name the failing
+//! element, e.g. `entries[1].value`.
+//! ```
+//! use zordon::prelude::*;
+//!
+//! #[derive(MutView)]
+//! struct Entry<'a> {
+//!     kind: ByteView<'a, u8>,
+//!     value: MulByteView<'a, u16, BigEnd>,
+//! }
+//!
+//! #[derive(MutView)]
+//! struct Table<'a> {
+//!     entries: ViewArray<Entry<'a>, 2>,
+//! }
+//!
+//! fn main() {
+//!     let mut input_buf = [0x01, 0x00, 0x10, 0x02, 0x00, 0x20];
+//!     let (table, _) = Table::mut_view(&mut input_buf);
+//!
+//!     assert_eq!(table.entries[1].value.val(), 0x20);
+//!     assert_eq!(table.entries.iter().map(|e| e.kind.val()).sum::<u8>(), 3);
+//!
+//!     let mut input_buf = [0x01, 0x00, 0x10, 0x02];
+//!     let err = Table::try_mut_view(&mut input_buf).err().unwrap();
+//!     assert_eq!(err.path.to_string(), "entries[1].value");
+//! }
+//! ```
+//! [`ViewArray`]: types::ViewArray
+//! [`VarViewArray`]: types::VarViewArray
+//! [`LazyViewArray`]: types::LazyViewArray
+//! [`LazyViewArrayRef`]: types::LazyViewArrayRef
+//!
+//! ## Bitfields
+//! Protocol headers often pack several fields into one word. [`BitView`] views `LEN` bits starting at
+//! bit `START` of a word of type T, numbered from the least significant bit ([`Lsb0`], the default) or the
+//! most significant bit ([`Msb0`]). Fields marked `#[zordon(shared)]` view the same bytes as the previous
+//! field rather than consuming new ones, so several bitfields can share a word. Setting a field leaves
+//! the rest of the word untouched. [`BitRef`] is the read-only counterpart.
+//! ```
+//! use zordon::prelude::*;
+//!
+//! #[derive(MutView)]
+//! struct Ipv4Start<'a> {
+//!     version: BitView<'a, u8, BigEnd, 0, 4, Msb0>,
+//!     #[zordon(shared)]
+//!     ihl: BitView<'a, u8, BigEnd, 4, 4, Msb0>,
+//!     tos: ByteView<'a, u8>,
+//! }
+//!
+//! fn main() {
+//!     let mut input_buf = [0x45, 0x00];
+//!     let (mut header, _) = Ipv4Start::mut_view(&mut input_buf);
+//!
+//!     assert_eq!(header.version.val(), 4);
+//!     assert_eq!(header.ihl.val(), 5);
+//!
+//!     header.ihl.set(6);
+//!     assert!(header.ihl.try_set(16).is_err());
+//!     assert_eq!(input_buf[0], 0x46);
+//! }
+//! ```
+//! A bit range which doesn't fit in T fails to compile.
+//! ```compile_fail
+//! # use zordon::prelude::*;
+//! let mut input_buf = [0x45];
+//! let _ = BitView::<u8, BigEnd, 6, 4>::mut_view(&mut input_buf);
+//! ```
+//! [`BitView`]: types::BitView
+//! [`BitRef`]: types::BitRef
+//! [`Lsb0`]: types::Lsb0
+//! [`Msb0`]: types::Msb0
+//!
+//! ## Flags
+//! [`FlagsView`] views a flags type, defined with [`flags!`], stored as an integer. `contains`, `insert`,
+//! `remove` and `toggle` work directly on the buffer. Bits which don't match any named flag are kept
+//! rather than dropped and are returned by `unknown`. [`FlagsRef`] is the read-only counterpart.
+//! ```
+//! use zordon::prelude::*;
+//!
+//! zordon::flags! {
+//!     pub struct TcpFlags: u8 {
+//!         const FIN = 0x01;
+//!         const SYN = 0x02;
+//!         const ACK = 0x10;
+//!     }
+//! }
+//!
+//! fn main() {
+//!     let mut input_buf = [0x12 | 0x80];
+//!     let (mut flags, _): (FlagsView<TcpFlags, BigEnd>, _) = FlagsView::mut_view(&mut input_buf);
+//!
+//!     assert!(flags.contains(TcpFlags::SYN | TcpFlags::ACK));
+//!     assert_eq!(flags.iter().count(), 2);
+//!     assert_eq!(flags.unknown(), 0x80);
+//!
+//!     flags.remove(TcpFlags::SYN);
+//!     assert_eq!(input_buf[0], 0x90);
+//! }
+//! ```
+//! [`FlagsView`]: types::FlagsView
+//! [`FlagsRef`]: types::FlagsRef
+//! [`flags!`]: crate::flags!
+//!
+//! ## Enums
+//! [`EnumView`] views a C-like enum stored as its discriminant. The [`EnumRepr`] derive maps an enum with
+//! a `#[repr(..)]` integer type to and from its discriminant. `val` returns the raw discriminant as the
+//! `Err` when it doesn't match any variant, so unknown values are never lost, and `set` writes the
+//! discriminant of a variant. [`EnumRef`] is the read-only counterpart.
+//! ```
+//! use zordon::prelude::*;
+//!
+//! #[derive(EnumRepr, Debug, PartialEq)]
+//! #[repr(u16)]
+//! enum EtherType {
+//!     Ipv4 = 0x0800,
+//!     Arp = 0x0806,
+//! }
+//!
+//! #[derive(MutView)]
+//! struct EthernetTail<'a> {
+//!     ether_type: EnumView<'a, EtherType, u16, BigEnd>,
+//! }
+//!
+//! fn main() {
+//!     let mut input_buf = [0x86, 0xDD];
+//!     let (mut eth, _) = EthernetTail::mut_view(&mut input_buf);
+//!
+//!     assert_eq!(eth.ether_type.val(), Err(0x86DD));
+//!
+//!     eth.ether_type.set(EtherType::Arp);
+//!     assert_eq!(eth.ether_type.val(), Ok(EtherType::Arp));
+//!     assert_eq!(input_buf, [0x08, 0x06]);
+//! }
+//! ```
+//! [`EnumView`]: types::EnumView
+//! [`EnumRef`]: types::EnumRef
+//!
+//! ## Tagged unions
+//! The [`MutView`] derive also applies to enums whose variants each hold one view. A variant is chosen
+//! by its `#[zordon(tag = ..)]` values, and a variant without a tag takes the bytes of any unknown tag,
+//! so it must have a runtime length like [`VarArrayView`]. Without such a variant an unknown tag fails
+//! with [`ViewErrorKind::UnknownTag`].
+//!
+//! With `#[zordon(tag_type = "..")]` the enum reads its own leading tag and gets the usual
+//! `mut_view` constructors. Otherwise the tag comes from an earlier field of a struct via a
+//! `#[zordon(tag = "..")]` field attribute, which takes the same expressions as `count` and `len`. A
+//! `len` on the field bounds the variant to that many bytes.
+//! ```
+//! use zordon::prelude::*;
+//!
+//! #[derive(MutView)]
+//! struct Ipv4Header<'a> {
+//!     ttl: ByteView<'a, u8>,
+//!     src: ArrayView<'a, [u8; 4]>,
+//! }
+//!
+//! #[derive(MutView)]
+//! #[zordon(tag_type = "ByteView<'a, u8>")]
+//! enum Packet<'a> {
+//!     #[zordon(tag = 0x01)]
+//!     Ipv4(Ipv4Header<'a>),
+//!     Unknown(VarArrayView<'a, u8>),
+//! }
+//!
+//! #[derive(MutView)]
+//! enum Body<'a> {
+//!     #[zordon(tag = 0x01)]
+//!     Ipv4(Ipv4Header<'a>),
+//!     Unknown(VarArrayView<'a, u8>),
+//! }
+//!
+//! #[derive(MutView)]
+//! struct Record<'a> {
+//!     kind: ByteView<'a, u8>,
+//!     len: ByteView<'a, u8>,
+//!     #[zordon(tag = "kind", len = "len")]
+//!     body: Body<'a>,
+//! }
+//!
+//! fn main() {
+//!     let mut input_buf = [0x01, 0x40, 0x0A, 0x00, 0x00, 0x01];
+//!     let (packet, _) = Packet::mut_view(&mut input_buf);
+//!
+//!     match packet {
+//!         Packet::Ipv4(mut ip) => ip.ttl.set(0x3F),
+//!         Packet::Unknown(_) => unreachable!(),
+//!     }
+//!     assert_eq!(input_buf[1], 0x3F);
+//!
+//!     let mut input_buf = [0x07, 0x02, 0xAA, 0xBB, 0xFF];
+//!     let (record, leftover) = Record::mut_view(&mut input_buf);
+//!
+//!     match record.body {
+//!         Body::Unknown(raw) => assert_eq!(raw.as_ref(), [0xAA, 0xBB]),
+//!         Body::Ipv4(_) => unreachable!(),
+//!     }
+//!     assert_eq!(leftover, [0xFF]);
+//! }
+//! ```
+//! [`ViewErrorKind::UnknownTag`]: error::ViewErrorKind::UnknownTag
+//!
 //! ## More examples
 //!
 //! The crate (NOT PUBLISHED YET) uses zordon for zero-copy parsing of the [PE](https://en.wikipedia.org/wiki/Portable_Executable) format.
@@ -196,9 +820,34 @@
 //! ## Features
 //!
 //! - Zero-copy -- Original buffer is split into mutable slices
-//! - Able to parse the following types: `[u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, [u8; _], &mut [u8]]`
-//! - The types u16..u128 and i16..i128 can be treated as little endian or big endian
+//! - Able to parse the following types: `[u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64, [u8; _], &mut [u8]]`
+//! - The types u16..u128, i16..i128, f32 and f64 can be treated as little endian, big endian or a byte order
+//!   chosen at runtime
 //! - Auto implementation of `mut_view` for structs via the [`MutView`] derive macro.
+//! - Variable length fields whose count or byte length is computed from earlier fields
+//! - Optional fields parsed only when a predicate over earlier fields holds
+//! - Magic number and constant field checks which reject mismatching input
+//! - Reserved field checks, with a `sanitize` method zeroing reserved bits
+//! - Padding and alignment between fields and at the end of a struct
+//! - Fields at an offset taken from an earlier field, with overlap detection
+//! - Offset views which follow an offset or RVA to the view it points to
+//! - Compile-time `SIZE` and `OFFSET_<FIELD>` constants for derived structs
+//! - Fallible `try_mut_view` constructors for parsing untrusted input without panicking
+//! - Read-only views for parsing data only available as a `&[u8]`
+//! - Endian aware arrays of multi byte values
+//! - Arrays of views, including derived structs, with fixed or runtime counts
+//! - Bitfield views, several of which can share the bytes of one word
+//! - Flags views which keep unknown bits
+//! - Enum views which keep unknown values, with the [`EnumRepr`] derive for C-like enums
+//! - Tagged unions chosen by a leading tag or an earlier field, with a raw fallback for unknown tags
+//!
+//! ## Cargo features
+//!
+//! - `alloc` (default) -- Enables [`SharedArrayView`] and [`SharedVarArrayView`], which alias a view via
+//!   `Rc<RefCell<_>>`, and [`VarViewArray`]. Every other view type and the [`MutView`] derive work
+//!   without an allocator when it is disabled.
+//!
+//! [`SharedVarArrayView`]: types::SharedVarArrayView
 //!
 //!
 //!
@@ -207,15 +856,19 @@
 #[cfg(feature = "std_unit_tests")]
 extern crate std;
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
+extern crate self as zordon;
 
+pub mod error;
 #[cfg(feature = "std_unit_tests")]
 mod tests;
 pub mod types;
-pub use mutview::MutView;
+pub use mutview::{EnumRepr, MutView};
 
 /// Import prelude to get everything needed into scope
 pub mod prelude {
-    pub use mutview::MutView;
+    pub use mutview::{EnumRepr, MutView};
+    pub use crate::error::*;
     pub use crate::types::*;
 }
@@ -1,11 +1,15 @@
 #[allow(unused_imports)]
-use crate::{types::*, MutView};
+use crate::{error::*, types::*, EnumRepr, MutView};
 #[allow(unused_attributes)]
 #[macro_use]
 #[allow(unused_imports)]
 #[macro_use]
 use assert_hex::assert_eq_hex;
+#[cfg(test)]
+use std::string::ToString;
+#[cfg(test)]
 use std::vec::Vec;
+#[cfg(test)]
 use std::vec;
 
 #[derive(MutView)]
@@ -171,7 +175,7 @@ fn arrayval_deref() {
 #[test]
 fn arrayval_deref_mut() {
     let mut buf = [0];
-    let (t, _): (ArrayView<[u8; 1]>, _) = ArrayView::mut_view(&mut buf);
+    let (mut t, _): (ArrayView<[u8; 1]>, _) = ArrayView::mut_view(&mut buf);
 
     t.as_mut_ref()[0] = 0xA;
 
@@ -200,7 +204,7 @@ fn vararrayval_deref() {
 #[test]
 fn vararrayval_deref_mut() {
     let buf = &mut [0 as u8] as &mut [u8];
-    let (t, _): (VarArrayView<u8>, _) = VarArrayView::mut_view(buf, buf.len());
+    let (mut t, _): (VarArrayView<u8>, _) = VarArrayView::mut_view(buf, buf.len());
 
     t.as_mut_ref()[0] = 0xA;
 
@@ -217,26 +221,1863 @@ fn vararrayval_set() {
     assert_eq_hex!(buf[0..4], new_data);
 }
 
-macro_rules! impl_mulbyteval_assign_test {
-    ($fname:ident, $oper:tt, $result:tt) => {
-        #[test]
-        fn $fname() {
-            let mut buf = vec![2; 30];
+#[cfg(feature = "alloc")]
+#[test]
+fn sharedarrayval_rc_clone() {
+    let mut buf = [0; 2];
+    let (t, _): (ArrayView<[u8; 2]>, _) = ArrayView::mut_view(&mut buf);
+    let shared = t.into_shared();
+    let alias = shared.rc_clone();
 
-            let (mut t, _) = LitEndUnsignTest::mut_view(&mut buf);
+    alias.borrow_mut()[1] = 0xB;
+    shared.as_mut_ref()[0] = 0xA;
 
-            t.unsigned_16 $oper 2;
-            t.unsigned_32 $oper 2;
-            t.unsigned_64 $oper 2;
+    assert_eq_hex!(**shared.as_ref(), [0xA, 0xB]);
+    assert_eq_hex!(**alias.borrow(), [0xA, 0xB]);
+}
 
-            assert_eq_hex!(buf[0..2], $result[0..2]);
-            assert_eq_hex!(buf[2..6], $result[2..6]);
-            assert_eq_hex!(buf[6..14], $result[6..14]);
+#[cfg(feature = "alloc")]
+#[test]
+fn sharedvararrayval_set() {
+    let buf = &mut [0 as u8; 4] as &mut [u8];
+    let (mut t, _): (SharedVarArrayView<u8>, _) = SharedVarArrayView::mut_view(buf, 3);
+    let alias = t.rc_clone();
+
+    t.set(&[0x1, 0x2, 0x3]);
+    assert_eq_hex!(**alias.borrow(), [0x1, 0x2, 0x3]);
+    drop(alias);
+    drop(t);
+    assert_eq_hex!(*buf, [0x1, 0x2, 0x3, 0x0]);
+}
+
+#[derive(MutView)]
+struct FloatTest<'a> {
+    pub lit_32: MulByteView<'a, f32, LitEnd>,
+    pub big_32: MulByteView<'a, f32, BigEnd>,
+    pub lit_64: MulByteView<'a, f64, LitEnd>,
+    pub big_64: MulByteView<'a, f64, BigEnd>,
+}
+
+#[test]
+fn mulbyteval_float() {
+    let mut buf = vec![0; 24];
+    let (mut t, _) = FloatTest::mut_view(&mut buf);
+
+    t.lit_32.set(1.5);
+    t.big_32.set(-2.25);
+    t.lit_64.set(core::f64::consts::PI);
+    t.big_64.set(f64::MIN_POSITIVE);
+
+    assert_eq!(t.lit_32.val(), 1.5);
+    assert_eq!(t.big_32.val(), -2.25);
+    assert_eq!(t.lit_64.val(), core::f64::consts::PI);
+    assert_eq!(t.big_64.val(), f64::MIN_POSITIVE);
+
+    t.lit_32 += 0.5;
+    t.big_64 *= 2.0;
+    assert_eq!(t.lit_32.val(), 2.0);
+    assert_eq!(t.big_64.val(), f64::MIN_POSITIVE * 2.0);
+
+    assert_eq_hex!(buf[0..4], 2.0f32.to_le_bytes());
+    assert_eq_hex!(buf[4..8], (-2.25f32).to_be_bytes());
+    assert_eq_hex!(buf[8..16], core::f64::consts::PI.to_le_bytes());
+    assert_eq_hex!(buf[16..24], (f64::MIN_POSITIVE * 2.0).to_be_bytes());
+}
+
+#[test]
+fn mulbyteval_float_nan_payload() {
+    const NAN_32: u32 = 0x7FA0_1234;
+    const NAN_64: u64 = 0xFFF8_0000_DEAD_BEEF;
+
+    let mut buf = vec![0; 24];
+    let (mut t, _) = FloatTest::mut_view(&mut buf);
+
+    t.lit_32.set(f32::from_bits(NAN_32));
+    t.big_32.set(f32::from_bits(NAN_32));
+    t.lit_64.set(f64::from_bits(NAN_64));
+    t.big_64.set(f64::from_bits(NAN_64));
+
+    assert_eq_hex!(t.lit_32.val().to_bits(), NAN_32);
+    assert_eq_hex!(t.big_32.val().to_bits(), NAN_32);
+    assert_eq_hex!(t.lit_64.val().to_bits(), NAN_64);
+    assert_eq_hex!(t.big_64.val().to_bits(), NAN_64);
+
+    assert_eq_hex!(buf[0..4], NAN_32.to_le_bytes());
+    assert_eq_hex!(buf[4..8], NAN_32.to_be_bytes());
+    assert_eq_hex!(buf[8..16], NAN_64.to_le_bytes());
+    assert_eq_hex!(buf[16..24], NAN_64.to_be_bytes());
+
+    let (r, _): (MulByteRef<f64, BigEnd>, _) = MulByteRef::view(&buf[16..]);
+    assert_eq_hex!(r.val().to_bits(), NAN_64);
+}
+
+#[test]
+fn f16_conversion() {
+    assert_eq_hex!(F16::from_f32(1.0).to_bits(), 0x3C00);
+    assert_eq_hex!(F16::from_f32(-2.0).to_bits(), 0xC000);
+    assert_eq_hex!(F16::from_f32(65504.0).to_bits(), 0x7BFF);
+    assert_eq_hex!(F16::from_f32(65520.0).to_bits(), 0x7C00);
+    assert_eq_hex!(F16::from_f32(f32::NEG_INFINITY).to_bits(), 0xFC00);
+    assert_eq_hex!(F16::from_f32(1.0 / 3.0).to_bits(), 0x3555);
+    // Ties round to even
+    assert_eq_hex!(F16::from_f32(1.0 + 2f32.powi(-11)).to_bits(), 0x3C00);
+    assert_eq_hex!(F16::from_f32(1.0 + 3.0 * 2f32.powi(-11)).to_bits(), 0x3C02);
+    // Subnormals
+    assert_eq_hex!(F16::from_f32(2f32.powi(-24)).to_bits(), 0x0001);
+    assert_eq_hex!(F16::from_f32(2f32.powi(-25)).to_bits(), 0x0000);
+    assert_eq_hex!(F16::from_f32(1.5 * 2f32.powi(-25)).to_bits(), 0x0001);
+    assert_eq_hex!(F16::from_f32(2f32.powi(-14) - 2f32.powi(-25)).to_bits(), 0x0400);
+    assert!(F16::from_f32(f32::NAN).to_f32().is_nan());
+
+    for bits in 0..=u16::MAX {
+        let h = F16::from_bits(bits);
+        if h.to_f32().is_nan() {
+            assert!(F16::from_f32(h.to_f32()).to_f32().is_nan());
+        } else {
+            assert_eq_hex!(F16::from_f32(h.to_f32()).to_bits(), bits);
         }
-    };
+    }
 }
 
-impl_mulbyteval_assign_test!(mulbyte_val_addassign, +=, [4, 2, 4, 2, 2, 2, 4, 2, 2, 2, 2, 2, 2, 2]);
-impl_mulbyteval_assign_test!(mulbyte_val_subassign, -=, [0, 2, 0, 2, 2, 2, 0, 2, 2, 2, 2, 2, 2, 2]);
-impl_mulbyteval_assign_test!(mulbyte_val_mulassign, *=, [4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4]);
-impl_mulbyteval_assign_test!(mulbyte_val_divassign, /=, [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1]);
+#[test]
+fn bf16_conversion() {
+    assert_eq_hex!(BF16::from_f32(1.0).to_bits(), 0x3F80);
+    assert_eq_hex!(BF16::from_f32(-2.0).to_bits(), 0xC000);
+    assert_eq_hex!(BF16::from_f32(f32::MAX).to_bits(), 0x7F80);
+    // Ties round to even
+    assert_eq_hex!(BF16::from_f32(1.0 + 2f32.powi(-8)).to_bits(), 0x3F80);
+    assert_eq_hex!(BF16::from_f32(1.0 + 3.0 * 2f32.powi(-8)).to_bits(), 0x3F82);
+    assert!(BF16::from_f32(f32::from_bits(0x7F80_0001)).to_f32().is_nan());
+
+    for bits in 0..=u16::MAX {
+        let h = BF16::from_bits(bits);
+        if h.to_f32().is_nan() {
+            assert!(BF16::from_f32(h.to_f32()).to_f32().is_nan());
+        } else {
+            assert_eq_hex!(BF16::from_f32(h.to_f32()).to_bits(), bits);
+        }
+    }
+}
+
+#[derive(MutView)]
+struct HalfTest<'a> {
+    pub lit_f16: MulByteView<'a, F16, LitEnd>,
+    pub big_f16: MulByteView<'a, F16, BigEnd>,
+    pub lit_bf16: MulByteView<'a, BF16, LitEnd>,
+    pub big_bf16: MulByteView<'a, BF16, BigEnd>,
+}
+
+#[test]
+fn mulbyteval_half() {
+    let mut buf = vec![0; 8];
+    let (mut t, _) = HalfTest::mut_view(&mut buf);
+
+    t.lit_f16.set(1.0);
+    t.big_f16.set(-0.5);
+    t.lit_bf16.set(1.0);
+    t.big_bf16.set(-0.5);
+
+    assert_eq!(t.lit_f16.val(), 1.0);
+    assert_eq!(t.big_f16.val(), -0.5);
+    assert_eq!(t.lit_bf16.val(), 1.0);
+    assert_eq!(t.big_bf16.val(), -0.5);
+    assert_eq_hex!(buf, [0x00, 0x3C, 0xB8, 0x00, 0x80, 0x3F, 0xBF, 0x00]);
+
+    let (r, _): (MulByteRef<F16, BigEnd>, _) = MulByteRef::view(&buf[2..]);
+    assert_eq!(r.val(), -0.5);
+}
+
+#[derive(MutView)]
+struct OddIntTest<'a> {
+    pub lit_u24: MulByteView<'a, U24, LitEnd>,
+    pub big_i24: MulByteView<'a, I24, BigEnd>,
+    pub big_u48: MulByteView<'a, U48, BigEnd>,
+    pub lit_i40: MulByteView<'a, I40, LitEnd>,
+    pub lit_i56: MulByteView<'a, I56, LitEnd>,
+}
+
+#[test]
+fn mulbyteval_odd_int_val() {
+    let mut buf = vec![
+        0x01, 0x02, 0x03, // lit_u24
+        0xFF, 0xFF, 0xFE, // big_i24
+        0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // big_u48
+        0x00, 0x00, 0x00, 0x00, 0x80, // lit_i40
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F, // lit_i56
+    ];
+    let (t, leftover) = OddIntTest::mut_view(&mut buf);
+
+    assert!(leftover.is_empty());
+    assert_eq_hex!(t.lit_u24.val(), 0x030201);
+    assert_eq!(t.big_i24.val(), -2);
+    assert_eq_hex!(t.big_u48.val(), 0x010203040506);
+    assert_eq!(t.lit_i40.val(), I40::MIN);
+    assert_eq!(t.lit_i56.val(), I56::MAX);
+}
+
+#[test]
+fn mulbyteval_odd_int_set() {
+    let mut buf = vec![0; 26];
+    let (mut t, _) = OddIntTest::mut_view(&mut buf);
+
+    t.lit_u24.set(0x030201);
+    t.big_i24.set(-2);
+    t.big_u48.set(0x010203040506);
+    t.lit_i40.set(-1);
+    // Values which don't fit are masked by set
+    t.lit_i56.set(0x7F00_0000_0000_0001);
+
+    assert_eq_hex!(buf[0..3], [0x01, 0x02, 0x03]);
+    assert_eq_hex!(buf[3..6], [0xFF, 0xFF, 0xFE]);
+    assert_eq_hex!(buf[6..12], [0x01, 0x02, 0x03, 0x04, 0x05, 0x06]);
+    assert_eq_hex!(buf[12..17], [0xFF; 5]);
+    assert_eq_hex!(buf[17..24], [0x01, 0, 0, 0, 0, 0, 0]);
+    assert_eq_hex!(buf[24..26], [0, 0]);
+}
+
+#[test]
+fn mulbyteval_odd_int_try_set() {
+    let mut buf = vec![0; 26];
+    let (mut t, _) = OddIntTest::mut_view(&mut buf);
+
+    assert_eq!(t.lit_u24.try_set(U24::MAX), Ok(()));
+    assert_eq!(
+        t.lit_u24.try_set(0x0100_0000),
+        Err(OutOfRange {
+            value: 0x0100_0000,
+            min: 0,
+            max: 0xFF_FFFF,
+        })
+    );
+    assert_eq!(t.lit_u24.val(), U24::MAX);
+
+    assert_eq!(t.big_i24.try_set(-0x80_0000), Ok(()));
+    assert!(t.big_i24.try_set(-0x80_0001).is_err());
+    assert!(t.big_i24.try_set(0x80_0000).is_err());
+    assert_eq!(t.big_i24.val(), I24::MIN);
+}
+
+#[derive(MutView)]
+struct DynEndInner<'a> {
+    pub unsigned_16: MulByteView<'a, u16, DynEnd>,
+    pub fixed_16: MulByteView<'a, u16, BigEnd>,
+}
+
+#[derive(MutView)]
+struct DynEndTest<'a> {
+    pub unsigned_32: MulByteView<'a, u32, DynEnd>,
+    pub half: MulByteView<'a, F16, DynEnd>,
+    pub odd: MulByteView<'a, I24, DynEnd>,
+    pub inner: DynEndInner<'a>,
+}
+
+#[derive(MutView)]
+struct DynEndRefTest<'a> {
+    pub unsigned_32: MulByteRef<'a, u32, DynEnd>,
+}
+
+#[test]
+fn mulbyteval_dynend() {
+    let data = [
+        0x01, 0x02, 0x03, 0x04, 0x3C, 0x00, 0xFF, 0xFF, 0xFE, 0x05, 0x06, 0x07, 0x08,
+    ];
+
+    let mut buf = data.to_vec();
+    let (mut t, _) = DynEndTest::mut_view_endian(&mut buf, Endian::Big);
+    assert_eq!(t.unsigned_32.endian(), Endian::Big);
+    assert_eq_hex!(t.unsigned_32.val(), 0x01020304);
+    assert_eq!(t.half.val(), 1.0);
+    assert_eq!(t.odd.val(), -2);
+    assert_eq_hex!(t.inner.unsigned_16.val(), 0x0506);
+    assert_eq_hex!(t.inner.fixed_16.val(), 0x0708);
+
+    t.inner.unsigned_16 += 1;
+    assert_eq_hex!(buf[9..11], [0x05, 0x07]);
+
+    let mut buf = data.to_vec();
+    let (mut t, _) = DynEndTest::mut_view_endian(&mut buf, Endian::Little);
+    assert_eq_hex!(t.unsigned_32.val(), 0x04030201);
+    assert_eq_hex!(t.inner.unsigned_16.val(), 0x0605);
+    assert_eq_hex!(t.inner.fixed_16.val(), 0x0708);
+
+    assert!(t.odd.try_set(0x800000).is_err());
+    assert!(t.odd.try_set(-0x123456).is_ok());
+    t.unsigned_32.set(0x0A0B0C0D);
+    assert_eq_hex!(buf[0..4], [0x0D, 0x0C, 0x0B, 0x0A]);
+    assert_eq_hex!(buf[6..9], [0xAA, 0xCB, 0xED]);
+
+    let (r, _) = DynEndRefTest::view_endian(&data, Endian::Little);
+    assert_eq_hex!(r.unsigned_32.val(), 0x04030201);
+}
+
+#[test]
+fn mulbyteval_dynend_missing_endian() {
+    let mut buf = vec![0; 16];
+    let err = DynEndTest::try_mut_view(&mut buf).err().unwrap();
+
+    assert_eq!(err.kind, ViewErrorKind::MissingEndian);
+    assert_eq!(err.path.iter().collect::<Vec<_>>(), ["unsigned_32"]);
+
+    let (t, _): (MulByteView<u16, DynEnd>, _) =
+        MulByteView::mut_view_endian(&mut buf, Endian::Big);
+    assert_eq!(t.endian(), Endian::Big);
+}
+
+#[derive(MutView)]
+struct NatMidEndTest<'a> {
+    pub native_32: MulByteView<'a, u32, NatEnd>,
+    pub mid_16: MulByteView<'a, u16, MidEnd>,
+    pub mid_32: MulByteView<'a, u32, MidEnd>,
+    pub mid_i32: MulByteView<'a, i32, MidEnd>,
+    pub mid_f64: MulByteView<'a, f64, MidEnd>,
+}
+
+#[test]
+fn mulbyteval_natend_midend() {
+    let mut buf = vec![0; 22];
+    let (mut t, _) = NatMidEndTest::mut_view(&mut buf);
+
+    t.native_32.set(U32_BE_RESULT);
+    t.mid_16.set(0x0A0B);
+    t.mid_32.set(0x0A0B0C0D);
+    t.mid_i32.set(-2);
+    t.mid_f64.set(1.0);
+
+    assert_eq_hex!(t.native_32.val(), U32_BE_RESULT);
+    assert_eq_hex!(t.mid_16.val(), 0x0A0B);
+    assert_eq_hex!(t.mid_32.val(), 0x0A0B0C0D);
+    assert_eq!(t.mid_i32.val(), -2);
+    assert_eq!(t.mid_f64.val(), 1.0);
+
+    assert_eq_hex!(buf[0..4], U32_BE_RESULT.to_ne_bytes());
+    assert_eq_hex!(buf[4..6], [0x0B, 0x0A]);
+    assert_eq_hex!(buf[6..10], [0x0B, 0x0A, 0x0D, 0x0C]);
+    assert_eq_hex!(buf[10..14], [0xFF, 0xFF, 0xFE, 0xFF]);
+    assert_eq_hex!(buf[14..22], [0x00, 0x00, 0xF0, 0x3F, 0x00, 0x00, 0x00, 0x00]);
+
+    let (r, _): (MulByteRef<u64, MidEnd>, _) = MulByteRef::view(&buf[14..]);
+    assert_eq_hex!(r.val(), 1.0f64.to_bits());
+}
+
+#[derive(MutView)]
+struct BitViewTest<'a> {
+    pub version: BitView<'a, u8, BigEnd, 0, 4, Msb0>,
+    #[zordon(shared)]
+    pub ihl: BitView<'a, u8, BigEnd, 4, 4, Msb0>,
+    pub flags: BitView<'a, u16, BigEnd, 0, 3, Msb0>,
+    #[zordon(shared)]
+    pub frag_offset: BitView<'a, u16, BigEnd, 3, 13, Msb0>,
+    pub low: BitView<'a, u16, LitEnd, 0, 4>,
+    #[zordon(shared)]
+    pub high: BitView<'a, u16, LitEnd, 12, 4>,
+    pub tail: ByteView<'a, u8>,
+}
+
+#[test]
+fn bitview_val() {
+    let mut buf = vec![0x45, 0x40, 0x12, 0x21, 0xF3, 0xAA];
+    let (t, leftover) = BitViewTest::mut_view(&mut buf);
+
+    assert_eq!(t.version.val(), 4);
+    assert_eq!(t.ihl.val(), 5);
+    assert_eq!(t.flags.val(), 0b010);
+    assert_eq_hex!(t.frag_offset.val(), 0x0012);
+    assert_eq!(t.low.val(), 0x1);
+    assert_eq!(t.high.val(), 0xF);
+    assert_eq_hex!(t.tail.val(), 0xAA);
+    assert_eq!(leftover.len(), 0);
+}
+
+#[test]
+fn bitview_set() {
+    let mut buf = vec![0x45, 0x40, 0x12, 0x21, 0xF3, 0xAA];
+    let (mut t, _) = BitViewTest::mut_view(&mut buf);
+
+    t.ihl.set(0xF);
+    t.flags.set(0);
+    t.frag_offset.set(0x1FFF);
+    t.high.set(0x12);
+
+    assert_eq!(t.version.val(), 4);
+    assert_eq!(t.ihl.val(), 0xF);
+    assert_eq_hex!(buf, [0x4F, 0x1F, 0xFF, 0x21, 0x23, 0xAA]);
+}
+
+#[test]
+fn bitview_try_set() {
+    let mut buf = vec![0x45];
+    let (mut t, _): (BitView<u8, BigEnd, 0, 4, Msb0>, _) = BitView::mut_view(&mut buf);
+
+    assert_eq!(
+        t.try_set(0x10),
+        Err(OutOfRange {
+            value: 0x10,
+            min: 0,
+            max: 0xF,
+        })
+    );
+    assert_eq!(t.try_set(0xF), Ok(()));
+    assert_eq!(BitView::<u8, BigEnd, 0, 4, Msb0>::max(), 0xF);
+    assert_eq_hex!(buf[0], 0xF5);
+}
+
+#[test]
+fn bitview_try_mut_view_short() {
+    let mut buf = vec![0x45];
+    let err = BitViewTest::try_mut_view(&mut buf).err().unwrap();
+
+    assert_eq!((err.needed, err.available, err.offset), (2, 0, 1));
+    assert_eq!(err.path.to_string(), "flags");
+}
+
+#[derive(MutView)]
+struct BitRefTest<'a> {
+    pub version: BitRef<'a, u8, BigEnd, 0, 4, Msb0>,
+    #[zordon(shared)]
+    pub ihl: BitRef<'a, u8, BigEnd, 4, 4, Msb0>,
+    #[zordon(len = "ihl")]
+    pub options: VarArrayRef<'a, u8>,
+    pub low: BitRef<'a, u16, LitEnd, 0, 4>,
+    #[zordon(shared, reserved)]
+    pub high: BitRef<'a, u16, LitEnd, 12, 4>,
+}
+
+#[test]
+fn bitref_val() {
+    let buf = [0x42, 0xAA, 0xBB, 0x01, 0x00, 0xCC];
+    let (t, leftover) = BitRefTest::view(&buf);
+
+    assert_eq!(t.version.val(), 4);
+    assert_eq!(t.ihl.val(), 2);
+    assert_eq!(t.options.as_ref(), [0xAA, 0xBB]);
+    assert_eq!(t.low.val(), 0x1);
+    assert_eq!(BitRef::<u8, BigEnd, 0, 4, Msb0>::max(), 0xF);
+    assert_eq!(leftover, [0xCC]);
+
+    let err = BitRefTest::try_view(&buf[..4]).err().unwrap();
+    assert_eq!((err.needed, err.available, err.offset), (2, 1, 3));
+    assert_eq!(err.path.to_string(), "low");
+
+    let buf = [0x42, 0xAA, 0xBB, 0x01, 0x10];
+    let err = BitRefTest::try_view(&buf).err().unwrap();
+    assert_eq!((err.kind, err.offset), (ViewErrorKind::ReservedNotZero, 3));
+    assert_eq!(err.path.to_string(), "high");
+}
+
+crate::flags! {
+    struct TestFlags: u16 {
+        const A = 0x0001;
+        const B = 0x0002;
+        const C = 0x0100;
+    }
+}
+
+#[derive(MutView)]
+struct FlagsViewTest<'a> {
+    pub le: FlagsView<'a, TestFlags, LitEnd>,
+    pub dynamic: FlagsView<'a, TestFlags, DynEnd>,
+}
+
+#[test]
+fn flagsview_modify() {
+    let mut buf = vec![0x03, 0x80, 0x01, 0x02];
+    let (mut t, _) = FlagsViewTest::mut_view_endian(&mut buf, Endian::Big);
+
+    assert!(t.le.contains(TestFlags::A | TestFlags::B));
+    assert!(!t.le.contains(TestFlags::C));
+    assert_eq_hex!(t.le.unknown(), 0x8000);
+    assert_eq_hex!(t.dynamic.val().bits(), 0x0102);
+
+    t.le.insert(TestFlags::C);
+    t.le.remove(TestFlags::A);
+    t.dynamic.toggle(TestFlags::A | TestFlags::B);
+
+    assert_eq_hex!(buf, [0x02, 0x81, 0x01, 0x01]);
+}
+
+#[test]
+fn flagsview_iter() {
+    let mut buf = vec![0x01, 0x81];
+    let (mut t, _): (FlagsView<TestFlags, LitEnd>, _) = FlagsView::mut_view(&mut buf);
+
+    assert_eq!(
+        t.iter().collect::<Vec<_>>(),
+        vec![TestFlags::A, TestFlags::C]
+    );
+    assert_eq!(
+        t.iter_names().map(|(n, _)| n).collect::<Vec<_>>(),
+        vec!["A", "C"]
+    );
+
+    t.set(TestFlags::B);
+    assert_eq!(t.iter().collect::<Vec<_>>(), vec![TestFlags::B]);
+    assert_eq!(t.unknown(), 0);
+}
+
+#[derive(EnumRepr, Debug, Clone, Copy, PartialEq)]
+#[repr(u16)]
+enum TestMachine {
+    I386 = 0x014C,
+    Amd64 = 0x8664,
+}
+
+#[derive(MutView)]
+struct EnumViewTest<'a> {
+    pub machine: EnumView<'a, TestMachine, u16, LitEnd>,
+    pub unknown: EnumView<'a, TestMachine, u16, BigEnd>,
+}
+
+#[test]
+fn enumview_val() {
+    let mut buf = vec![0x64, 0x86, 0x12, 0x34];
+    let (t, _) = EnumViewTest::mut_view(&mut buf);
+
+    assert_eq!(t.machine.val(), Ok(TestMachine::Amd64));
+    assert_eq!(t.unknown.val(), Err(0x1234));
+    assert_eq_hex!(t.unknown.raw(), 0x1234);
+}
+
+#[test]
+fn enumview_set() {
+    let mut buf = vec![0x64, 0x86, 0x12, 0x34];
+    let (mut t, _) = EnumViewTest::mut_view(&mut buf);
+
+    t.machine.set(TestMachine::I386);
+    t.unknown.set_raw(0xFFFF);
+    assert_eq!(t.unknown.val(), Err(0xFFFF));
+
+    t.unknown.set(TestMachine::Amd64);
+    assert_eq!(t.unknown.val(), Ok(TestMachine::Amd64));
+    assert_eq_hex!(buf, [0x4C, 0x01, 0x86, 0x64]);
+}
+
+#[derive(MutView)]
+struct EnumRefTest<'a> {
+    #[zordon(eq = 0x8664)]
+    pub machine: EnumRef<'a, TestMachine, u16, LitEnd>,
+    pub unknown: EnumRef<'a, TestMachine, u16, BigEnd>,
+}
+
+#[test]
+fn enumref_val() {
+    let buf = [0x64, 0x86, 0x12, 0x34];
+    let (t, _) = EnumRefTest::view(&buf);
+
+    assert_eq!(t.machine.val(), Ok(TestMachine::Amd64));
+    assert_eq!(t.unknown.val(), Err(0x1234));
+    assert_eq!(t.unknown.raw(), 0x1234);
+    assert_eq!(t.unknown.len_value(), Some(0x1234));
+
+    let err = EnumRefTest::try_view(&buf[..3]).err().unwrap();
+    assert_eq!((err.offset, err.path.to_string()), (2, "unknown".into()));
+
+    let buf = [0x4C, 0x01, 0x12, 0x34];
+    let err = EnumRefTest::try_view(&buf).err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "unexpected value 0x14c for view at offset 0x0 (machine), expected 0x8664"
+    );
+}
+
+#[derive(MutView)]
+struct MulArrayViewTest<'a> {
+    pub le: MulArrayView<'a, [u32; 2], LitEnd>,
+    pub be: MulArrayView<'a, [i16; 2], BigEnd>,
+}
+
+#[test]
+fn mularrayview_get_set() {
+    let mut buf = vec![0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0xFF, 0xFE, 0x00, 0x01];
+    let (mut t, leftover) = MulArrayViewTest::mut_view(&mut buf);
+
+    assert_eq!(leftover.len(), 0);
+    assert_eq!(t.le.len(), 2);
+    assert_eq!(t.le.get(1), Some(2));
+    assert_eq!(t.le.get(2), None);
+    assert_eq!(t.be.iter().collect::<Vec<_>>(), vec![-2, 1]);
+
+    t.le.set(0, 0x0A0B0C0D);
+    for mut e in t.be.iter_mut() {
+        e += 1;
+    }
+
+    assert_eq_hex!(buf[..4], [0x0D, 0x0C, 0x0B, 0x0A]);
+    assert_eq_hex!(buf[8..], [0xFF, 0xFF, 0x00, 0x02]);
+}
+
+#[derive(MutView)]
+struct MulArrayRefTest<'a> {
+    pub le: MulArrayRef<'a, [u32; 2], LitEnd>,
+    pub count: ByteRef<'a, u8>,
+    #[zordon(count = "count", reserved)]
+    pub be: VarMulArrayRef<'a, i16, BigEnd>,
+}
+
+#[test]
+fn mularrayref_get() {
+    let buf = [0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0xEE];
+    let (t, leftover) = MulArrayRefTest::view(&buf);
+
+    assert_eq!(leftover, [0xEE]);
+    assert_eq!(t.le.len(), 2);
+    assert_eq!(t.le.get(1), Some(2));
+    assert_eq!(t.le.get(2), None);
+    assert_eq!(t.be.iter().collect::<Vec<_>>(), vec![0, 0]);
+
+    let mut out = [0; 2];
+    t.le.copy_to(&mut out);
+    assert_eq!(out, [1, 2]);
+
+    let (v, _): (VarMulArrayRef<i16, BigEnd>, _) = VarMulArrayRef::view(&[0x00, 0x01, 0x03, 0xFF], 2);
+    assert_eq!(v.iter().collect::<Vec<_>>(), vec![1, 0x03FF]);
+
+    let buf = [0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x01];
+    let err = MulArrayRefTest::try_view(&buf).err().unwrap();
+    assert_eq!((err.kind, err.offset), (ViewErrorKind::ReservedNotZero, 9));
+}
+
+#[test]
+fn varmularrayview_copy() {
+    let mut buf = vec![0; 8];
+    let (mut t, _): (VarMulArrayView<u16, BigEnd>, _) = VarMulArrayView::mut_view(&mut buf, 3);
+
+    t.copy_from(&[0x0102, 0x0304, 0x0506]);
+    let mut out = [0; 3];
+    t.copy_to(&mut out);
+
+    assert_eq_hex!(out, [0x0102, 0x0304, 0x0506]);
+    assert_eq_hex!(buf, [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x00, 0x00]);
+}
+
+#[test]
+#[should_panic]
+fn varmularrayview_copy_len_mismatch() {
+    let mut buf = vec![0; 4];
+    let (mut t, _): (VarMulArrayView<u16, BigEnd>, _) = VarMulArrayView::mut_view(&mut buf, 2);
+
+    t.copy_from(&[0x0102]);
+}
+
+#[derive(MutView)]
+struct ArrayEntry<'a> {
+    pub kind: ByteView<'a, u8>,
+    pub value: MulByteView<'a, u16, BigEnd>,
+}
+
+#[derive(MutView)]
+struct ViewArrayTest<'a> {
+    pub count: ByteView<'a, u8>,
+    pub entries: ViewArray<ArrayEntry<'a>, 2>,
+}
+
+#[test]
+fn viewarray_struct() {
+    let mut buf = vec![0x02, 0x01, 0x00, 0x10, 0x02, 0x00, 0x20, 0xFF];
+    let (mut t, leftover) = ViewArrayTest::mut_view(&mut buf);
+
+    assert_eq!(leftover.len(), 1);
+    assert_eq!(t.entries.len(), 2);
+    assert_eq_hex!(t.entries[1].value.val(), 0x0020);
+    assert_eq!(
+        t.entries.iter().map(|e| e.kind.val()).collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+
+    for e in t.entries.iter_mut() {
+        e.value += 1;
+    }
+
+    assert_eq_hex!(buf, [0x02, 0x01, 0x00, 0x11, 0x02, 0x00, 0x21, 0xFF]);
+}
+
+#[test]
+fn viewarray_short() {
+    let mut buf = vec![0x02, 0x01, 0x00, 0x10, 0x02, 0x00];
+    let err = ViewArrayTest::try_mut_view(&mut buf).err().unwrap();
+
+    assert_eq!((err.needed, err.available, err.offset), (2, 1, 5));
+    assert_eq!(err.path.to_string(), "entries[1].value");
+
+    let err = ViewArray::<RefInner, 2>::try_view(&REF_TESTDATA).err().unwrap();
+    assert_eq!(err.path.to_string(), "[1].signed_32");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn varviewarray_struct() {
+    let mut buf = vec![0x01, 0x00, 0x10, 0x02, 0x00, 0x20, 0x03, 0x00, 0x30];
+    let count = 2;
+    let (mut entries, leftover): (VarViewArray<ArrayEntry>, _) =
+        VarViewArray::mut_view(&mut buf, count);
+
+    assert_eq!(leftover.len(), 3);
+    assert_eq!(entries.len(), 2);
+    entries[0].kind.set(0xAA);
+    assert_eq_hex!(buf[0], 0xAA);
+
+    let (entries, _): (VarViewArray<RefInner>, _) = VarViewArray::view(&REF_TESTDATA, 1);
+    assert_eq_hex!(entries[0].unsigned_16.val(), 0x0102);
+
+    let err = VarViewArray::<RefInner>::try_view(&REF_TESTDATA, usize::MAX)
+        .err()
+        .unwrap();
+    assert_eq!(err.path.to_string(), "[1].signed_32");
+}
+
+#[test]
+fn lazyviewarray_struct() {
+    let mut buf = vec![0x01, 0x00, 0x10, 0x02, 0x00, 0x20, 0x03, 0x00, 0x30];
+    let (mut entries, leftover): (LazyViewArray<ArrayEntry>, _) =
+        LazyViewArray::mut_view(&mut buf, 2);
+
+    assert_eq!(leftover.len(), 3);
+    assert_eq!(entries.len(), 2);
+
+    for e in entries.iter_mut() {
+        e.unwrap().value += 1;
+    }
+
+    assert_eq!(
+        entries
+            .iter_mut()
+            .map(|e| e.unwrap().kind.val())
+            .collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+    assert_eq_hex!(buf[..6], [0x01, 0x00, 0x11, 0x02, 0x00, 0x21]);
+
+    let mut buf = vec![0x01, 0x00, 0x10, 0x02, 0x00];
+    let err = LazyViewArray::<ArrayEntry>::try_mut_view(&mut buf, 2)
+        .err()
+        .unwrap();
+    assert_eq!((err.path.to_string(), err.offset), ("[1].value".to_string(), 4));
+}
+
+#[test]
+fn lazyviewarray_get_fixed_size() {
+    let mut buf = vec![0x01, 0x00, 0x10, 0x02, 0x00, 0x20, 0x03, 0x00, 0x30];
+    let (mut entries, _): (LazyViewArray<ArrayEntry>, _) = LazyViewArray::mut_view(&mut buf, 3);
+
+    entries.get_mut(2).unwrap().unwrap().value += 1;
+    assert_eq!(entries.get_mut(1).unwrap().unwrap().kind.val(), 2);
+    assert!(entries.get_mut(3).is_none());
+    assert_eq_hex!(buf[6..], [0x03, 0x00, 0x31]);
+
+    let (entries, _): (LazyViewArrayRef<RefInner>, _) =
+        LazyViewArrayRef::view(&REF_TESTDATA[..8], 1);
+    assert_eq_hex!(entries.get(0).unwrap().unsigned_16.val(), 0x0102);
+    assert!(entries.get(1).is_none());
+}
+
+#[derive(MutView)]
+struct LazyCountTest<'a> {
+    pub count: ByteView<'a, u8>,
+    #[zordon(count = "count")]
+    pub entries: LazyViewArray<'a, ArrayEntry<'a>>,
+    pub len: ByteView<'a, u8>,
+    #[zordon(len = "len")]
+    pub values: LazyViewArray<'a, MulByteView<'a, u16, LitEnd>>,
+}
+
+#[test]
+fn lazyviewarray_derive_count_len() {
+    let mut buf = vec![0x01, 0x05, 0x00, 0x10, 0x04, 0x01, 0x00, 0x02, 0x00, 0xFF];
+    let (mut t, leftover) = LazyCountTest::mut_view(&mut buf);
+
+    assert_eq!(leftover, [0xFF]);
+    assert_eq!((t.entries.len(), t.values.len()), (1, 2));
+
+    for v in t.values.iter_mut() {
+        v.unwrap().set(0x1234);
+    }
+    assert_eq_hex!(buf[5..9], [0x34, 0x12, 0x34, 0x12]);
+
+    let mut buf = vec![0x00, 0x03, 0x01, 0x00, 0x02];
+    let err = LazyCountTest::try_mut_view(&mut buf).err().unwrap();
+    assert_eq!((err.kind, err.offset), (ViewErrorKind::Truncated, 4));
+    assert_eq!(err.path.to_string(), "values[1]");
+}
+
+#[test]
+fn lazyviewarrayref_struct() {
+    let (entries, leftover): (LazyViewArrayRef<RefInner>, _) =
+        LazyViewArrayRef::view(&REF_TESTDATA[3..], 1);
+
+    assert_eq!(leftover.len(), 1);
+    assert_eq!(entries.len(), 1);
+    assert_eq_hex!(
+        entries.iter().map(|e| e.signed_32.val()).collect::<Vec<_>>(),
+        vec![-1]
+    );
+
+    let err = LazyViewArrayRef::<RefInner>::try_view(&REF_TESTDATA, 2)
+        .err()
+        .unwrap();
+    assert_eq!(err.path.to_string(), "[1].signed_32");
+}
+
+#[derive(MutView)]
+struct CountTest<'a> {
+    pub num_entries: MulByteView<'a, u16, BigEnd>,
+    pub len: ByteView<'a, i8>,
+    #[zordon(count = "num_entries")]
+    pub entries: VarMulArrayView<'a, u16, LitEnd>,
+    #[zordon(len = "len")]
+    pub bytes: VarArrayView<'a, u8>,
+    #[zordon(count = 2)]
+    pub fixed: VarArrayView<'a, u8>,
+}
+
+#[test]
+fn derive_count_len() {
+    let mut buf = vec![0x00, 0x02, 0x03, 0x01, 0x00, 0x02, 0x00, 0xA, 0xB, 0xC, 0xD, 0xE, 0xF];
+    let (t, leftover) = CountTest::mut_view(&mut buf);
+
+    assert_eq!(t.entries.iter().collect::<Vec<_>>(), vec![1, 2]);
+    assert_eq!(t.bytes.as_ref(), [0xA, 0xB, 0xC]);
+    assert_eq!(t.fixed.as_ref(), [0xD, 0xE]);
+    assert_eq!(leftover, [0xF]);
+}
+
+#[test]
+fn derive_count_len_errors() {
+    let mut buf = vec![0x00, 0x02, 0xFF, 0x01, 0x00, 0x02, 0x00];
+    let err = CountTest::try_mut_view(&mut buf).err().unwrap();
+
+    assert_eq!(err.kind, ViewErrorKind::InvalidLength);
+    assert_eq!(err.offset, 7);
+    assert_eq!(err.path.to_string(), "bytes");
+
+    let mut buf = vec![0x00, 0x03, 0x00, 0x01, 0x00, 0x02, 0x00];
+    let err = CountTest::try_mut_view(&mut buf).err().unwrap();
+
+    assert_eq!((err.needed, err.available, err.offset), (6, 4, 3));
+    assert_eq!(err.path.to_string(), "entries");
+}
+
+#[derive(MutView)]
+struct RefCountTest<'a> {
+    pub len: ByteRef<'a, u8>,
+    #[zordon(len = "len")]
+    pub entries: VarArrayRef<'a, u8>,
+}
+
+#[cfg(feature = "alloc")]
+#[derive(MutView)]
+struct VarViewArrayCountTest<'a> {
+    pub count: BitView<'a, u8, BigEnd, 0, 4>,
+    #[zordon(shared)]
+    pub len: BitView<'a, u8, BigEnd, 4, 4>,
+    #[zordon(count = "count")]
+    pub by_count: VarViewArray<ArrayEntry<'a>>,
+    #[zordon(len = "len")]
+    pub by_len: VarViewArray<ArrayEntry<'a>>,
+}
+
+#[test]
+fn derive_view_len() {
+    let (t, leftover) = RefCountTest::view(&REF_TESTDATA);
+
+    assert_eq!(t.entries.as_ref(), [0x2]);
+    assert_eq!(leftover.len(), REF_TESTDATA.len() - 2);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn derive_varviewarray_count_len() {
+    let mut buf = vec![0x61, 0x01, 0x00, 0x10, 0x02, 0x00, 0x20, 0x03, 0x00, 0x30, 0xFF];
+    let (t, leftover) = VarViewArrayCountTest::mut_view(&mut buf);
+
+    assert_eq!(t.by_count.len(), 1);
+    assert_eq!(t.by_len.len(), 2);
+    assert_eq_hex!(t.by_len[1].value.val(), 0x0030);
+    assert_eq!(leftover, [0xFF]);
+
+    let mut buf = vec![0x41, 0x01, 0x00, 0x10, 0x02, 0x00, 0x20, 0x03];
+    let err = VarViewArrayCountTest::try_mut_view(&mut buf).err().unwrap();
+
+    assert_eq!((err.needed, err.available, err.offset), (2, 0, 8));
+    assert_eq!(err.path.to_string(), "by_len[1].value");
+}
+
+#[derive(MutView)]
+struct LenExprTest<'a> {
+    pub version: BitView<'a, u8, BigEnd, 0, 4, Msb0>,
+    #[zordon(shared)]
+    pub ihl: BitView<'a, u8, BigEnd, 4, 4, Msb0>,
+    pub size: MulByteView<'a, u32, LitEnd>,
+    #[zordon(len = "ihl * 4 - 20")]
+    pub options: VarArrayView<'a, u8>,
+    #[zordon(count = "(size - 8) / (version - 4) % 3")]
+    pub data: VarArrayView<'a, u8>,
+}
+
+#[test]
+fn derive_len_expr() {
+    let mut buf = vec![0x66, 0x0A, 0x00, 0x00, 0x00, 0x1, 0x2, 0x3, 0x4, 0xA, 0xB, 0xC];
+    let (t, leftover) = LenExprTest::mut_view(&mut buf);
+
+    assert_eq!(t.options.as_ref(), [0x1, 0x2, 0x3, 0x4]);
+    assert_eq!(t.data.as_ref(), [0xA]);
+    assert_eq!(leftover, [0xB, 0xC]);
+}
+
+#[test]
+fn derive_len_expr_overflow() {
+    // ihl * 4 - 20 underflows.
+    let mut buf = vec![0x64, 0x0A, 0x00, 0x00, 0x00];
+    let err = LenExprTest::try_mut_view(&mut buf).err().unwrap();
+
+    assert_eq!(err.kind, ViewErrorKind::LengthOverflow);
+    assert_eq!((err.offset, err.path.to_string()), (5, "options".into()));
+    assert_eq!(
+        err.to_string(),
+        "length overflowed for view at offset 0x5 (options)"
+    );
+
+    // size - 8 underflows.
+    let mut buf = vec![0x65, 0x07, 0x00, 0x00, 0x00];
+    let err = LenExprTest::try_mut_view(&mut buf).err().unwrap();
+    assert_eq!(err.kind, ViewErrorKind::LengthOverflow);
+
+    // Division by zero.
+    let mut buf = vec![0x45, 0x10, 0x00, 0x00, 0x00];
+    let err = LenExprTest::try_mut_view(&mut buf).err().unwrap();
+    assert_eq!((err.kind, err.path.to_string()), (ViewErrorKind::LengthOverflow, "data".into()));
+}
+
+const HDR_LEN: usize = 2;
+const NEG_COUNT: i8 = -1;
+
+mod consts {
+    pub const TRAILER_COUNT: u16 = 1;
+}
+
+#[derive(MutView)]
+struct ConstLenTest<'a> {
+    pub len: ByteView<'a, u8>,
+    #[zordon(len = "len - HDR_LEN")]
+    pub body: VarArrayView<'a, u8>,
+    #[zordon(count = "consts::TRAILER_COUNT")]
+    pub trailer: VarArrayView<'a, u8>,
+}
+
+#[derive(MutView)]
+struct NegConstLenTest<'a> {
+    #[zordon(count = "NEG_COUNT")]
+    pub data: VarArrayView<'a, u8>,
+}
+
+#[test]
+fn derive_len_expr_const() {
+    let mut buf = vec![0x04, 0xA, 0xB, 0xC, 0xD];
+    let (t, leftover) = ConstLenTest::mut_view(&mut buf);
+
+    assert_eq!(t.body.as_ref(), [0xA, 0xB]);
+    assert_eq!(t.trailer.as_ref(), [0xC]);
+    assert_eq!(leftover, [0xD]);
+
+    let mut buf = vec![0x00];
+    let err = NegConstLenTest::try_mut_view(&mut buf).err().unwrap();
+    assert_eq!((err.kind, err.path.to_string()), (ViewErrorKind::InvalidLength, "data".into()));
+}
+
+#[derive(MutView)]
+struct ShiftExprTest<'a> {
+    pub scale: ByteView<'a, u8>,
+    #[zordon(len = "(scale << 62) >> 62")]
+    pub data: VarArrayView<'a, u8>,
+}
+
+#[test]
+fn derive_len_expr_shift_overflow() {
+    let mut buf = vec![0x01, 0xAA];
+    let (t, _) = ShiftExprTest::mut_view(&mut buf);
+    assert_eq!(t.data.as_ref(), [0xAA]);
+
+    // 4 << 62 shifts the set bit out of a usize.
+    let mut buf = vec![0x04, 0xAA];
+    let err = ShiftExprTest::try_mut_view(&mut buf).err().unwrap();
+    assert_eq!((err.kind, err.path.to_string()), (ViewErrorKind::LengthOverflow, "data".into()));
+}
+
+#[derive(MutView)]
+struct CondTest<'a> {
+    pub version: ByteView<'a, u8>,
+    pub flags: FlagsView<'a, TestFlags, LitEnd>,
+    #[zordon(if = "version >= 2")]
+    pub v2: Option<MulByteView<'a, u16, BigEnd>>,
+    #[zordon(if = "flags & 0x1 != 0")]
+    pub ext: Option<ArrayEntry<'a>>,
+    #[zordon(if = "version == 1 || !(flags & 0x100 == 0)", len = "version")]
+    pub extra: Option<VarArrayView<'a, u8>>,
+    pub tail: ByteView<'a, u8>,
+}
+
+#[test]
+fn derive_if() {
+    let mut buf = vec![0x01, 0x00, 0x00, 0xAA, 0xFF];
+    let (t, leftover) = CondTest::mut_view(&mut buf);
+
+    assert!(t.v2.is_none());
+    assert!(t.ext.is_none());
+    assert_eq!(t.extra.unwrap().as_ref(), [0xAA]);
+    assert_eq_hex!(t.tail.val(), 0xFF);
+    assert_eq!(leftover.len(), 0);
+
+    let mut buf = vec![0x02, 0x01, 0x00, 0x12, 0x34, 0x07, 0x00, 0x08, 0xFF];
+    let (t, _) = CondTest::mut_view(&mut buf);
+
+    assert_eq_hex!(t.v2.unwrap().val(), 0x1234);
+    assert_eq_hex!(t.ext.unwrap().value.val(), 0x0008);
+    assert!(t.extra.is_none());
+    assert_eq_hex!(t.tail.val(), 0xFF);
+}
+
+#[test]
+fn derive_if_short() {
+    let mut buf = vec![0x03, 0x01, 0x00, 0x12, 0x34, 0x07];
+    let err = CondTest::try_mut_view(&mut buf).err().unwrap();
+
+    assert_eq!((err.needed, err.available, err.offset), (2, 0, 6));
+    assert_eq!(err.path.to_string(), "ext.value");
+}
+
+type OptionalHeader<'a> = MulByteView<'a, u16, BigEnd>;
+
+#[derive(MutView)]
+struct OptionNameTest<'a> {
+    pub header: OptionalHeader<'a>,
+    pub tail: ByteView<'a, u8>,
+}
+
+#[test]
+fn derive_option_like_name() {
+    let mut buf = vec![0x12, 0x34, 0xFF];
+    let (t, leftover) = OptionNameTest::mut_view(&mut buf);
+
+    assert_eq_hex!(t.header.val(), 0x1234);
+    assert_eq_hex!(t.tail.val(), 0xFF);
+    assert_eq!(leftover.len(), 0);
+}
+
+#[derive(MutView)]
+#[zordon(tag_type = "ByteView<'a, u8>")]
+enum TaggedTest<'a> {
+    #[zordon(tag = 0x01)]
+    Entry(ArrayEntry<'a>),
+    #[zordon(tag = 0x02, tag = 0x03)]
+    Word(MulByteView<'a, u32, BigEnd>),
+    Raw(VarArrayView<'a, u8>),
+}
+
+#[test]
+fn derive_enum_leading_tag() {
+    let mut buf = vec![0x01, 0x07, 0x12, 0x34, 0xFF];
+    let (t, leftover) = TaggedTest::mut_view(&mut buf);
+
+    match t {
+        TaggedTest::Entry(e) => {
+            assert_eq_hex!(e.kind.val(), 0x07);
+            assert_eq_hex!(e.value.val(), 0x1234);
+        }
+        _ => panic!("expected Entry"),
+    }
+    assert_eq!(leftover, [0xFF]);
+
+    let mut buf = vec![0x03, 0x12, 0x34, 0x56, 0x78];
+    match TaggedTest::mut_view(&mut buf).0 {
+        TaggedTest::Word(mut w) => {
+            assert_eq_hex!(w.val(), 0x12345678);
+            w.set(0xAABBCCDD);
+        }
+        _ => panic!("expected Word"),
+    }
+    assert_eq_hex!(buf, [0x03, 0xAA, 0xBB, 0xCC, 0xDD]);
+
+    // Unknown tags fall back to the untagged variant, which takes the rest of the buffer.
+    let mut buf = vec![0x09, 0x01, 0x02];
+    let (t, leftover) = TaggedTest::mut_view(&mut buf);
+
+    match t {
+        TaggedTest::Raw(r) => assert_eq!(r.as_ref(), [0x01, 0x02]),
+        _ => panic!("expected Raw"),
+    }
+    assert_eq!(leftover.len(), 0);
+}
+
+#[test]
+fn derive_enum_leading_tag_short() {
+    let mut buf = vec![0x02, 0x12, 0x34];
+    let err = TaggedTest::try_mut_view(&mut buf).err().unwrap();
+
+    assert_eq!((err.needed, err.available, err.offset), (4, 2, 1));
+    assert_eq!(err.path.to_string(), "Word");
+}
+
+#[derive(MutView)]
+enum TlvBody<'a> {
+    #[zordon(tag = 0x10)]
+    Short(MulByteView<'a, u16, BigEnd>),
+    #[zordon(tag = 0x20)]
+    Entry(ArrayEntry<'a>),
+}
+
+#[derive(MutView)]
+struct TlvTest<'a> {
+    pub kind: ByteView<'a, u8>,
+    pub len: ByteView<'a, u8>,
+    #[zordon(tag = "kind", len = "len")]
+    pub body: TlvBody<'a>,
+    #[zordon(tag = "kind + 0x10")]
+    pub next: TlvBody<'a>,
+}
+
+#[test]
+fn derive_enum_field_tag() {
+    // The body is given 4 bytes but only uses 2, the rest are skipped.
+    let mut buf = vec![0x10, 0x04, 0x12, 0x34, 0x00, 0x00, 0x05, 0xAB, 0xCD, 0xFF];
+    let (t, leftover) = TlvTest::mut_view(&mut buf);
+
+    match t.body {
+        TlvBody::Short(s) => assert_eq_hex!(s.val(), 0x1234),
+        _ => panic!("expected Short"),
+    }
+    match t.next {
+        TlvBody::Entry(e) => assert_eq_hex!(e.value.val(), 0xABCD),
+        _ => panic!("expected Entry"),
+    }
+    assert_eq!(leftover, [0xFF]);
+}
+
+#[test]
+fn derive_enum_field_tag_errors() {
+    let mut buf = vec![0x30, 0x02, 0x12, 0x34, 0x00];
+    let err = TlvTest::try_mut_view(&mut buf).err().unwrap();
+
+    assert_eq!((err.kind, err.offset), (ViewErrorKind::UnknownTag, 2));
+    assert_eq!(err.to_string(), "unknown tag for view at offset 0x2 (body)");
+
+    // The len is longer than the buffer.
+    let mut buf = vec![0x10, 0x08, 0x12, 0x34];
+    let err = TlvTest::try_mut_view(&mut buf).err().unwrap();
+
+    assert_eq!((err.needed, err.available, err.offset), (8, 2, 2));
+    assert_eq!(err.path.to_string(), "body");
+
+    // The len is too short for the variant.
+    let mut buf = vec![0x10, 0x01, 0x12, 0x34, 0x00, 0x00, 0x00];
+    let err = TlvTest::try_mut_view(&mut buf).err().unwrap();
+
+    assert_eq!((err.needed, err.available, err.offset), (2, 1, 2));
+    assert_eq!(err.path.to_string(), "body.Short");
+}
+
+#[derive(MutView)]
+#[zordon(tag_type = "MulByteRef<'a, u16, LitEnd>")]
+enum TaggedRefTest<'a> {
+    #[zordon(tag = 0x0100)]
+    Byte(ByteRef<'a, u8>),
+    Raw(VarArrayRef<'a, u8>),
+}
+
+#[test]
+fn derive_enum_view() {
+    let (t, leftover) = TaggedRefTest::view(&[0x00, 0x01, 0x42, 0x43]);
+
+    match t {
+        TaggedRefTest::Byte(b) => assert_eq_hex!(b.val(), 0x42),
+        _ => panic!("expected Byte"),
+    }
+    assert_eq!(leftover, [0x43]);
+
+    match TaggedRefTest::view(&[0x01, 0x00, 0x42]).0 {
+        TaggedRefTest::Raw(r) => assert_eq!(r.as_ref(), [0x42]),
+        _ => panic!("expected Raw"),
+    }
+}
+
+#[derive(MutView)]
+struct MagicTest<'a> {
+    #[zordon(magic = b"MZ")]
+    pub magic: ArrayView<'a, [u8; 2]>,
+    #[zordon(eq = 0x4550)]
+    pub sig: MulByteView<'a, u16, BigEnd>,
+    pub version: BitView<'a, u8, BigEnd, 0, 4>,
+    #[zordon(shared, eq = 0)]
+    pub reserved: BitView<'a, u8, BigEnd, 4, 4>,
+    #[zordon(if = "version >= 2", eq = 0xFF)]
+    pub ext: Option<ByteView<'a, u8>>,
+}
+
+#[test]
+fn derive_magic_eq() {
+    let mut buf = vec![b'M', b'Z', 0x45, 0x50, 0x02, 0xFF, 0x00];
+    let (t, leftover) = MagicTest::mut_view(&mut buf);
+
+    assert_eq!(t.magic.as_ref(), b"MZ");
+    assert_eq_hex!(t.sig.val(), 0x4550);
+    assert_eq_hex!(t.ext.unwrap().val(), 0xFF);
+    assert_eq!(leftover, [0x00]);
+
+    // The ext field isn't parsed so isn't checked.
+    let mut buf = vec![b'M', b'Z', 0x45, 0x50, 0x01];
+    assert!(MagicTest::try_mut_view(&mut buf).is_ok());
+}
+
+#[test]
+fn derive_magic_eq_errors() {
+    let unexpected = |expected: FieldValue, found: FieldValue| ViewErrorKind::UnexpectedValue {
+        expected,
+        found,
+    };
+
+    let mut buf = vec![b'P', b'K', 0x45, 0x50, 0x01];
+    let err = MagicTest::try_mut_view(&mut buf).err().unwrap();
+
+    assert_eq!(
+        (err.kind, err.offset),
+        (unexpected((*b"MZ").into(), (*b"PK").into()), 0)
+    );
+    assert_eq!(
+        err.to_string(),
+        "unexpected value b\"PK\" for view at offset 0x0 (magic), expected b\"MZ\""
+    );
+
+    let mut buf = vec![b'M', b'Z', 0x50, 0x45, 0x01];
+    let err = MagicTest::try_mut_view(&mut buf).err().unwrap();
+    assert_eq!(
+        (err.kind, err.offset),
+        (unexpected(0x4550u16.into(), 0x5045u16.into()), 2)
+    );
+    assert_eq!(
+        err.to_string(),
+        "unexpected value 0x5045 for view at offset 0x2 (sig), expected 0x4550"
+    );
+
+    let mut buf = vec![b'M', b'Z', 0x45, 0x50, 0x11];
+    let err = MagicTest::try_mut_view(&mut buf).err().unwrap();
+    assert_eq!(
+        (err.kind, err.offset),
+        (unexpected(0u8.into(), 1u8.into()), 4)
+    );
+    assert_eq!(err.path.to_string(), "reserved");
+
+    let mut buf = vec![b'M', b'Z', 0x45, 0x50, 0x02, 0xFE];
+    let err = MagicTest::try_mut_view(&mut buf).err().unwrap();
+    assert_eq!(
+        (err.kind, err.offset),
+        (unexpected(0xFFu8.into(), 0xFEu8.into()), 5)
+    );
+    assert_eq!(err.path.to_string(), "ext");
+}
+
+#[test]
+fn field_value_display() {
+    assert_eq!(FieldValue::from(-2i32).to_string(), "-2");
+    assert_eq!(FieldValue::from(-2i32).as_i128(), Some(-2));
+    assert_eq!(FieldValue::from(1.5f32).to_string(), "1.5");
+    assert_eq!(FieldValue::from(*b"\x7fELF").to_string(), "b\"\\x7fELF\"");
+    assert_eq!(FieldValue::from(*b"\x7fELF").as_bytes(), Some(&b"\x7fELF"[..]));
+
+    let long = FieldValue::from(*b"0123456789");
+    assert!(long.is_truncated());
+    assert_eq!(long.to_string(), "b\"01234567...\"");
+
+    let wide = FieldValue::from(u128::MAX);
+    assert_eq!((wide.is_truncated(), wide.as_i128()), (true, None));
+    assert_eq!(wide.to_string(), "0x...ffffffffffffffff");
+    assert_eq!(FieldValue::from(u128::from(u64::MAX)).as_i128(), Some(u64::MAX.into()));
+}
+
+#[derive(MutView)]
+struct ReservedTest<'a> {
+    pub version: BitView<'a, u8, BigEnd, 0, 4>,
+    #[zordon(shared, reserved)]
+    pub flags_reserved: BitView<'a, u8, BigEnd, 4, 4>,
+    #[zordon(reserved)]
+    pub reserved: ArrayView<'a, [u8; 2]>,
+    #[zordon(if = "version >= 2", reserved)]
+    pub ext_reserved: Option<MulByteView<'a, u16, LitEnd>>,
+    pub tail: ByteView<'a, u8>,
+}
+
+#[test]
+fn derive_reserved() {
+    let mut buf = vec![0x02, 0x00, 0x00, 0x00, 0x00, 0xFF];
+    let (t, leftover) = ReservedTest::mut_view(&mut buf);
+
+    assert_eq_hex!(t.version.val(), 0x2);
+    assert_eq_hex!(t.tail.val(), 0xFF);
+    assert_eq!(leftover.len(), 0);
+
+    let mut buf = vec![0x12, 0x00, 0x00, 0x00, 0x00, 0xFF];
+    let err = ReservedTest::try_mut_view(&mut buf).err().unwrap();
+    assert_eq!((err.kind, err.offset), (ViewErrorKind::ReservedNotZero, 0));
+    assert_eq!(
+        err.to_string(),
+        "reserved bits not zero for view at offset 0x0 (flags_reserved)"
+    );
+
+    let mut buf = vec![0x02, 0x00, 0x01, 0x00, 0x00, 0xFF];
+    let err = ReservedTest::try_mut_view(&mut buf).err().unwrap();
+    assert_eq!((err.kind, err.path.to_string()), (ViewErrorKind::ReservedNotZero, "reserved".into()));
+
+    let mut buf = vec![0x02, 0x00, 0x00, 0x00, 0x80, 0xFF];
+    let err = ReservedTest::try_mut_view(&mut buf).err().unwrap();
+    assert_eq!((err.kind, err.offset), (ViewErrorKind::ReservedNotZero, 3));
+}
+
+#[test]
+fn derive_reserved_sanitize() {
+    let mut buf = vec![0xF2, 0xAA, 0xBB, 0xCC, 0xDD, 0xFF];
+    let ctx = ParseCtx::default().ignore_reserved();
+    let (mut t, _) = ReservedTest::try_mut_view_ctx(&mut buf, &ctx).unwrap();
+
+    assert_eq_hex!(t.flags_reserved.val(), 0xF);
+    t.sanitize();
+
+    assert_eq_hex!(buf, [0x02, 0x00, 0x00, 0x00, 0x00, 0xFF]);
+    assert!(ReservedTest::try_mut_view(&mut buf).is_ok());
+}
+
+#[derive(MutView)]
+#[zordon(align = 4)]
+struct PadTest<'a> {
+    pub kind: ByteView<'a, u8>,
+    #[zordon(pad = 1)]
+    pub len: ByteView<'a, u8>,
+    #[zordon(align = 4)]
+    pub value: MulByteView<'a, u16, BigEnd>,
+    #[zordon(pad = "len")]
+    pub tail: ByteView<'a, u8>,
+}
+
+#[test]
+fn derive_pad_align() {
+    let mut buf = vec![0x01, 0xEE, 0x02, 0xEE, 0x12, 0x34, 0xEE, 0xEE, 0x56, 0xEE, 0xEE, 0xEE, 0xFF];
+    let (t, leftover) = PadTest::mut_view(&mut buf);
+
+    assert_eq_hex!(t.kind.val(), 0x01);
+    assert_eq_hex!(t.len.val(), 0x02);
+    assert_eq_hex!(t.value.val(), 0x1234);
+    assert_eq_hex!(t.tail.val(), 0x56);
+    assert_eq!(leftover, [0xFF]);
+
+    // The end is already aligned.
+    let mut buf = vec![0x01, 0xEE, 0x00, 0xEE, 0x12, 0x34, 0x56, 0xEE];
+    let (_, leftover) = PadTest::mut_view(&mut buf);
+    assert_eq!(leftover.len(), 0);
+}
+
+#[test]
+fn derive_pad_align_short() {
+    let mut buf = vec![0x01, 0xEE, 0x02];
+    let err = PadTest::try_mut_view(&mut buf).err().unwrap();
+
+    assert_eq!((err.needed, err.available, err.offset), (1, 0, 3));
+    assert_eq!(err.path.to_string(), "value");
+
+    // The end padding is missing.
+    let mut buf = vec![0x01, 0xEE, 0x00, 0xEE, 0x12, 0x34, 0x56];
+    let err = PadTest::try_mut_view(&mut buf).err().unwrap();
+
+    assert_eq!((err.needed, err.available, err.offset), (1, 0, 7));
+    assert!(err.path.is_empty());
+}
+
+#[derive(MutView)]
+#[zordon(abs_align = 2)]
+struct AbsAlignTest<'a> {
+    pub kind: ByteView<'a, u8>,
+    #[zordon(abs_align = 4)]
+    pub value: ByteView<'a, u8>,
+}
+
+#[test]
+fn derive_abs_align() {
+    let mut buf = vec![0x01, 0xEE, 0xEE, 0xEE, 0x02, 0xEE, 0xFF];
+    let (t, leftover) = AbsAlignTest::mut_view(&mut buf);
+
+    assert_eq_hex!(t.value.val(), 0x02);
+    assert_eq!(leftover, [0xFF]);
+
+    // Starting at offset 3 of the original buffer, value is already aligned but the end isn't.
+    let mut buf = vec![0x01, 0x02, 0xEE, 0xFF];
+    let ctx = ParseCtx::new(3);
+    let (t, leftover) = AbsAlignTest::try_mut_view_ctx(&mut buf, &ctx).unwrap();
+
+    assert_eq_hex!(t.value.val(), 0x02);
+    assert_eq!(leftover, [0xFF]);
+}
+
+#[derive(MutView)]
+struct AtTest<'a> {
+    #[zordon(magic = b"MZ")]
+    pub magic: ArrayView<'a, [u8; 2]>,
+    pub lfanew: ByteView<'a, u8>,
+    #[zordon(at = "lfanew")]
+    pub header: ArrayEntry<'a>,
+    pub next: ByteView<'a, u8>,
+}
+
+#[test]
+fn derive_at() {
+    let mut buf = vec![b'M', b'Z', 0x05, 0xEE, 0xEE, 0x01, 0x12, 0x34, 0x02, 0xFF];
+    let (t, leftover) = AtTest::mut_view(&mut buf);
+
+    assert_eq_hex!(t.header.kind.val(), 0x01);
+    assert_eq_hex!(t.header.value.val(), 0x1234);
+    assert_eq_hex!(t.next.val(), 0x02);
+    assert_eq!(leftover, [0xFF]);
+
+    // The header may directly follow lfanew.
+    let mut buf = vec![b'M', b'Z', 0x03, 0x01, 0x12, 0x34, 0x02];
+    assert!(AtTest::try_mut_view(&mut buf).is_ok());
+}
+
+#[test]
+fn derive_at_errors() {
+    // The header would alias lfanew.
+    let mut buf = vec![b'M', b'Z', 0x02, 0x01, 0x12, 0x34, 0x02];
+    let err = AtTest::try_mut_view(&mut buf).err().unwrap();
+
+    assert_eq!((err.kind, err.offset), (ViewErrorKind::Overlap, 2));
+    assert_eq!(
+        err.to_string(),
+        "overlapping view at offset 0x2 (header)"
+    );
+
+    // The header is past the end of the buffer.
+    let mut buf = vec![b'M', b'Z', 0x10, 0x01];
+    let err = AtTest::try_mut_view(&mut buf).err().unwrap();
+
+    assert_eq!((err.kind, err.needed, err.available), (ViewErrorKind::Truncated, 13, 1));
+    assert_eq!(err.path.to_string(), "header");
+}
+
+#[derive(MutView)]
+struct AbsAtTest<'a> {
+    pub phoff: ByteView<'a, u8>,
+    pub shoff: ByteView<'a, u8>,
+    #[zordon(abs_at = "phoff")]
+    pub ph: ByteView<'a, u8>,
+    #[zordon(abs_at = "shoff")]
+    pub sh: ByteView<'a, u8>,
+}
+
+#[test]
+fn derive_abs_at() {
+    // The struct starts 2 bytes into the original buffer.
+    let mut buf = vec![0x05, 0x07, 0xEE, 0x0A, 0xEE, 0x0B];
+    let ctx = ParseCtx::new(2);
+    let (t, leftover) = AbsAtTest::try_mut_view_ctx(&mut buf, &ctx).unwrap();
+
+    assert_eq_hex!(t.ph.val(), 0x0A);
+    assert_eq_hex!(t.sh.val(), 0x0B);
+    assert_eq!(leftover.len(), 0);
+
+    // The offsets must be increasing.
+    let mut buf = vec![0x07, 0x05, 0xEE, 0x0A, 0xEE, 0x0B];
+    let err = AbsAtTest::try_mut_view_ctx(&mut buf, &ctx).err().unwrap();
+
+    assert_eq!((err.kind, err.offset), (ViewErrorKind::Overlap, 5));
+    assert_eq!(err.path.to_string(), "sh");
+}
+
+#[derive(MutView)]
+struct OffsetTest<'a> {
+    pub count: ByteView<'a, u8>,
+    pub entry: OffsetView<'a, ArrayEntry<'a>, LitEnd>,
+    pub wide: OffsetView<'a, ArrayEntry<'a>, BigEnd, u64>,
+}
+
+#[test]
+fn offsetview_follow() {
+    let mut buf = vec![
+        0x01, 0x0F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x0D, 0x07, 0x12,
+        0x34,
+    ];
+    let (mut t, base) = OffsetTest::mut_view(&mut buf);
+
+    assert_eq!(t.entry.offset(), Some(0x0F));
+    assert_eq!(t.entry.len_value(), Some(0x0F));
+
+    // The base starts after the 13 byte struct.
+    let ctx = ParseCtx::new(13);
+    let mut e = t.wide.try_follow_ctx(base, &ctx).unwrap();
+    assert_eq_hex!(e.kind.val(), 0x07);
+    e.value.set(0xABCD);
+
+    let err = t.entry.try_follow_ctx(base, &ctx).err().unwrap();
+    assert_eq!((err.needed, err.available, err.offset), (2, 0, 0x10));
+    assert_eq!(err.path.to_string(), "value");
+
+    t.entry.try_set(13).unwrap();
+    let e = t.entry.try_follow_ctx(base, &ctx).unwrap();
+    assert_eq_hex!(e.value.val(), 0xABCD);
+    assert_eq!(&buf[1..5], [0x0D, 0x00, 0x00, 0x00]);
+}
+
+#[test]
+fn offsetview_follow_map_errors() {
+    let mut buf = vec![0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    let (mut t, _) = OffsetTest::mut_view(&mut buf);
+    let mut base = vec![0x00, 0x01, 0x00, 0x02];
+
+    // Offsets from 4 onwards map to the start of the base.
+    let e = t.entry.try_follow_map(&mut base, &ParseCtx::default(), |o| o.checked_sub(4)).unwrap();
+    assert_eq_hex!(e.value.val(), 0x0002);
+
+    let err = t.entry.try_follow_map(&mut base, &ParseCtx::default(), |_| None).err().unwrap();
+    assert_eq!((err.kind, err.offset), (ViewErrorKind::InvalidOffset, 5));
+    assert_eq!(err.to_string(), "invalid offset 0x5 for view");
+
+    // The offset is before the start of the base.
+    let err = t.entry.try_follow_ctx(&mut base, &ParseCtx::new(8)).err().unwrap();
+    assert_eq!((err.kind, err.offset), (ViewErrorKind::InvalidOffset, 5));
+
+    // The offset is past the end of the base.
+    let err = t.entry.try_follow(&mut base).err().unwrap();
+    assert_eq!((err.kind, err.offset), (ViewErrorKind::InvalidOffset, 5));
+
+    // An offset too large for a usize keeps its low bits.
+    let mut wide = vec![
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x07,
+    ];
+    let (ptr, _) = OffsetView::<ByteView<u8>, BigEnd, u128>::mut_view(&mut wide);
+    let err = ptr.try_follow(&mut base).err().unwrap();
+    assert_eq!((err.kind, err.offset), (ViewErrorKind::InvalidOffset, 7));
+
+    assert_eq!(
+        t.entry.try_set(usize::MAX),
+        Err(OutOfRange {
+            value: usize::MAX as i128,
+            min: 0,
+            max: u32::MAX as i128
+        })
+    );
+}
+
+#[test]
+fn offsetview_follow_ref() {
+    let mut buf = vec![0x00, 0x02];
+    let (ptr, _) = OffsetView::<ByteRef<u8>, BigEnd, u16>::mut_view(&mut buf);
+    let base = [0x00, 0x11, 0x22];
+
+    assert_eq_hex!(ptr.try_follow_ref(&base).unwrap().val(), 0x22);
+    assert_eq_hex!(
+        ptr.try_follow_ref_map(&base, &ParseCtx::default(), |o| Some(o - 1)).unwrap().val(),
+        0x11
+    );
+}
+
+#[derive(MutView)]
+struct OffsetRefTest<'a> {
+    pub count: ByteRef<'a, u8>,
+    pub entry: OffsetRef<'a, MulByteRef<'a, u16, BigEnd>, LitEnd, u16>,
+}
+
+#[test]
+fn offsetref_follow() {
+    let buf = [0x01, 0x02, 0x00, 0xAB, 0xCD];
+    let (t, base) = OffsetRefTest::view(&buf);
+
+    assert_eq!(t.count.val(), 1);
+    assert_eq!(t.entry.offset(), Some(2));
+    assert_eq!(t.entry.len_value(), Some(2));
+    assert_eq!(OffsetRef::<ByteRef<u8>, LitEnd, u16>::SIZE, 2);
+
+    let err = t.entry.try_follow(base).err().unwrap();
+    assert_eq!((err.needed, err.available, err.offset), (2, 0, 2));
+
+    // The base starts after the 3 byte struct.
+    let ctx = ParseCtx::new(3);
+    let err = t.entry.try_follow_ctx(base, &ctx).err().unwrap();
+    assert_eq!((err.kind, err.offset), (ViewErrorKind::InvalidOffset, 2));
+
+    let e = t.entry.try_follow_map(base, &ctx, |o| o.checked_add(1)).unwrap();
+    assert_eq_hex!(e.val(), 0xABCD);
+}
+
+#[derive(MutView)]
+#[zordon(align = 4)]
+struct LayoutTest<'a> {
+    pub kind: ByteView<'a, u8>,
+    pub flags: FlagsView<'a, TestFlags, LitEnd>,
+    #[zordon(align = 4)]
+    pub entries: ViewArray<ArrayEntry<'a>, 2>,
+    pub bits: BitView<'a, u8, BigEnd, 0, 4>,
+    #[zordon(shared)]
+    pub more_bits: BitView<'a, u8, BigEnd, 4, 4>,
+    pub none: Option<ByteView<'a, u8>>,
+    #[zordon(pad = 1)]
+    pub ptr: OffsetView<'a, ArrayEntry<'a>, LitEnd, u16>,
+}
+
+const _: () = assert!(LayoutTest::SIZE == 16);
+
+#[test]
+fn derive_layout_consts() {
+    assert_eq!(
+        [
+            LayoutTest::OFFSET_KIND,
+            LayoutTest::OFFSET_FLAGS,
+            LayoutTest::OFFSET_ENTRIES,
+            LayoutTest::OFFSET_BITS,
+            LayoutTest::OFFSET_MORE_BITS,
+            LayoutTest::OFFSET_NONE,
+            LayoutTest::OFFSET_PTR,
+        ],
+        [0, 1, 4, 10, 10, 11, 12]
+    );
+    assert_eq!(<LayoutTest as FixedSize>::SIZE, 16);
+    assert_eq!(ArrayEntry::SIZE, 3);
+
+    let mut buf = vec![0; 20];
+    let (_, leftover) = LayoutTest::mut_view(&mut buf);
+    assert_eq!(leftover.len(), 20 - LayoutTest::SIZE);
+
+    // Offsets are known up to the first field with a runtime position.
+    assert_eq!(
+        [PadTest::OFFSET_KIND, PadTest::OFFSET_LEN, PadTest::OFFSET_VALUE],
+        [0, 2, 4]
+    );
+    assert_eq!(MagicTest::OFFSET_RESERVED, 4);
+    assert_eq!(MagicTest::OFFSET_EXT, 5);
+}
+
+#[derive(MutView)]
+struct NestedLayoutTest<'a> {
+    pub kind: ByteView<'a, u8>,
+    pub body: LenExprTest<'a>,
+    pub tail: ByteView<'a, u8>,
+}
+
+#[test]
+fn derive_layout_consts_partial() {
+    // The body has no fixed size, but offsets up to and including it are still usable.
+    assert_eq!(
+        [NestedLayoutTest::OFFSET_KIND, NestedLayoutTest::OFFSET_BODY],
+        [0, 1]
+    );
+}
+
+macro_rules! impl_mulbyteval_assign_test {
+    ($fname:ident, $oper:tt, $result:tt) => {
+        #[test]
+        fn $fname() {
+            let mut buf = vec![2; 30];
+
+            let (mut t, _) = LitEndUnsignTest::mut_view(&mut buf);
+
+            t.unsigned_16 $oper 2;
+            t.unsigned_32 $oper 2;
+            t.unsigned_64 $oper 2;
+
+            assert_eq_hex!(buf[0..2], $result[0..2]);
+            assert_eq_hex!(buf[2..6], $result[2..6]);
+            assert_eq_hex!(buf[6..14], $result[6..14]);
+        }
+    };
+}
+
+impl_mulbyteval_assign_test!(mulbyte_val_addassign, +=, [4, 2, 4, 2, 2, 2, 4, 2, 2, 2, 2, 2, 2, 2]);
+impl_mulbyteval_assign_test!(mulbyte_val_subassign, -=, [0, 2, 0, 2, 2, 2, 0, 2, 2, 2, 2, 2, 2, 2]);
+impl_mulbyteval_assign_test!(mulbyte_val_mulassign, *=, [4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4]);
+impl_mulbyteval_assign_test!(mulbyte_val_divassign, /=, [1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1]);
+
+#[derive(MutView)]
+struct TryInner<'a> {
+    pub unsigned_8: ByteView<'a, u8>,
+    pub unsigned_16: MulByteView<'a, u16, LitEnd>,
+}
+
+#[derive(MutView)]
+struct TryOuter<'a> {
+    pub arr: ArrayView<'a, [u8; 2]>,
+    pub inner: TryInner<'a>,
+}
+
+#[test]
+fn try_mut_view_ok() {
+    let mut buf = vec![0x1, 0x2, 0x3, 0x4, 0x5, 0x6];
+    let (t, leftover) = TryOuter::try_mut_view(&mut buf).unwrap();
+
+    assert_eq_hex!(*t.arr.as_ref(), [0x1, 0x2]);
+    assert_eq_hex!(t.inner.unsigned_8.val(), 0x3);
+    assert_eq_hex!(t.inner.unsigned_16.val(), 0x0504);
+    assert_eq_hex!(*leftover, [0x6]);
+}
+
+#[test]
+fn try_mut_view_short() {
+    let mut buf = vec![0; 3];
+    assert_eq!(
+        ByteView::<u8>::try_mut_view(&mut buf[..0]).unwrap_err(),
+        ViewError::new(1, 0)
+    );
+    assert_eq!(
+        MulByteView::<u32, BigEnd>::try_mut_view(&mut buf).unwrap_err(),
+        ViewError::new(4, 3)
+    );
+    assert_eq!(
+        ArrayView::<[u8; 4]>::try_mut_view(&mut buf).unwrap_err(),
+        ViewError::new(4, 3)
+    );
+    assert_eq!(
+        VarArrayView::<u8>::try_mut_view(&mut buf, 5).unwrap_err(),
+        ViewError::new(5, 3)
+    );
+
+    let mut buf = vec![0; 4];
+    let err = TryOuter::try_mut_view(&mut buf).err().unwrap();
+    assert_eq!((err.needed, err.available, err.offset), (2, 1, 3));
+    assert_eq!(err.path.iter().collect::<Vec<_>>(), ["inner", "unsigned_16"]);
+}
+
+#[derive(MutView)]
+struct TryHygiene<'a> {
+    pub buf: ByteView<'a, u8>,
+    pub ctx: ByteView<'a, u8>,
+    pub len: MulByteView<'a, u16, BigEnd>,
+}
+
+#[test]
+fn try_mut_view_field_names() {
+    let mut buf = vec![0x1, 0x2, 0x3, 0x4, 0x5];
+    let (t, leftover) = TryHygiene::try_mut_view(&mut buf).unwrap();
+
+    assert_eq_hex!(t.buf.val(), 0x1);
+    assert_eq_hex!(t.ctx.val(), 0x2);
+    assert_eq_hex!(t.len.val(), 0x0304);
+    assert_eq_hex!(*leftover, [0x5]);
+}
+
+#[test]
+fn try_mut_view_ctx_offset() {
+    let mut buf = vec![0; 4];
+    let err = TryOuter::try_mut_view_ctx(&mut buf, &ParseCtx::new(0x10))
+        .err()
+        .unwrap();
+
+    assert_eq!(err.offset, 0x13);
+    assert_eq!(
+        err.to_string(),
+        "needed 2 bytes at offset 0x13 (inner.unsigned_16) but only 1 available"
+    );
+}
+
+fn nested_path(ctx: &ParseCtx, depth: usize) -> FieldPath {
+    match depth {
+        0 => ctx.path(),
+        _ => nested_path(&ctx.field("f", 0), depth - 1),
+    }
+}
+
+#[test]
+fn field_path_truncated() {
+    let path = nested_path(&ParseCtx::new(0), MAX_PATH_DEPTH);
+    assert!(!path.is_truncated());
+    assert_eq!(path.iter().count(), MAX_PATH_DEPTH);
+
+    let path = nested_path(&ParseCtx::new(0), MAX_PATH_DEPTH + 1);
+    assert!(path.is_truncated());
+    assert_eq!(path.iter().count(), MAX_PATH_DEPTH);
+    assert!(path.to_string().starts_with("...f.f"));
+}
+
+#[derive(MutView)]
+struct RefInner<'a> {
+    pub unsigned_16: MulByteRef<'a, u16, BigEnd>,
+    pub signed_32: MulByteRef<'a, i32, LitEnd>,
+}
+
+#[derive(MutView)]
+struct RefOuter<'a> {
+    pub unsigned_8: ByteRef<'a, u8>,
+    pub arr: ArrayRef<'a, [u8; 2]>,
+    pub inner: RefInner<'a>,
+    pub opt: Option<u8>,
+}
+
+static REF_TESTDATA: [u8; 10] = [0x1, 0x2, 0x3, 0x4, 0x5, 0xFF, 0xFF, 0xFF, 0xFF, 0x6];
+
+#[test]
+fn byteref_val() {
+    let (b, leftover): (ByteRef<u8>, _) = ByteRef::view(&REF_TESTDATA);
+    assert_eq!(b.val(), U8_RESULT);
+    assert_eq!(leftover.len(), REF_TESTDATA.len() - 1);
+
+    let (b, _): (ByteRef<i8>, _) = ByteRef::view(&REF_TESTDATA[5..]);
+    assert_eq!(b.val(), -1);
+}
+
+#[test]
+fn mulbyteref_val() {
+    let buf = &REF_TESTDATA[1..];
+    let (t, _): (MulByteRef<u16, LitEnd>, _) = MulByteRef::view(buf);
+    assert_eq_hex!(t.val(), 0x0302);
+
+    let (t, _): (MulByteRef<u32, BigEnd>, _) = MulByteRef::view(buf);
+    assert_eq_hex!(t.val(), 0x02030405);
+}
+
+#[test]
+fn arrayref_vararrayref_deref() {
+    let (t, leftover): (ArrayRef<[u8; 4]>, _) = ArrayRef::view(&REF_TESTDATA);
+    assert_eq_hex!(*t.as_ref(), [0x1, 0x2, 0x3, 0x4]);
+
+    let (t, _): (VarArrayRef<u8>, _) = VarArrayRef::view(leftover, 2);
+    assert_eq_hex!(*t.as_ref(), [0x5, 0xFF]);
+}
+
+#[test]
+fn derive_view() {
+    let (t, leftover) = RefOuter::view(&REF_TESTDATA);
+
+    assert_eq_hex!(t.unsigned_8.val(), 0x1);
+    assert_eq_hex!(*t.arr.as_ref(), [0x2, 0x3]);
+    assert_eq_hex!(t.inner.unsigned_16.val(), 0x0405);
+    assert_eq!(t.inner.signed_32.val(), -1);
+    assert_eq!(t.opt, None);
+    assert_eq_hex!(*leftover, [0x6]);
+}
+
+#[test]
+fn derive_try_view_short() {
+    let err = RefOuter::try_view(&REF_TESTDATA[..8]).err().unwrap();
+
+    assert_eq!((err.needed, err.available, err.offset), (4, 3, 5));
+    assert_eq!(err.path.iter().collect::<Vec<_>>(), ["inner", "signed_32"]);
+    assert_eq!(
+        VarArrayRef::<u8>::try_view(&REF_TESTDATA, 11).unwrap_err(),
+        ViewError::new(11, 10)
+    );
+}
+
+#[derive(MutView)]
+struct FlagsRefTest<'a> {
+    pub le: FlagsRef<'a, TestFlags, LitEnd>,
+    #[zordon(eq = 0x0002)]
+    pub dynamic: FlagsRef<'a, TestFlags, DynEnd>,
+}
+
+#[test]
+fn flagsref_val() {
+    let buf = [0x01, 0x81, 0x00, 0x02];
+    let (t, _) = FlagsRefTest::view_endian(&buf, Endian::Big);
+
+    assert!(t.le.contains(TestFlags::A | TestFlags::C));
+    assert_eq!(t.le.iter().collect::<Vec<_>>(), vec![TestFlags::A, TestFlags::C]);
+    assert_eq!(t.le.unknown(), 0x8000);
+    assert_eq!(t.dynamic.val(), TestFlags::B);
+
+    let err = FlagsRefTest::try_view_endian(&buf[..3], Endian::Big).err().unwrap();
+    assert_eq!((err.offset, err.path.to_string()), (2, "dynamic".into()));
+
+    let err = FlagsRefTest::try_view_endian(&buf, Endian::Little).err().unwrap();
+    assert_eq!((err.offset, err.path.to_string()), (2, "dynamic".into()));
+    assert!(matches!(err.kind, ViewErrorKind::UnexpectedValue { .. }));
+}
@@ -2,12 +2,210 @@
 //!
 //! Contains all of the custom types implemented by `zordon`.
 
+#[cfg(feature = "alloc")]
 use alloc::rc::Rc;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 #[allow(unused_imports)]
 use byteorder::{BigEndian, ByteOrder, LittleEndian, ReadBytesExt, WriteBytesExt};
+#[cfg(feature = "alloc")]
 use core::cell::{Ref, RefCell, RefMut};
+use core::cell::Cell;
+use core::convert::TryInto;
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
 
+use crate::error::{FieldValue, OutOfRange, ParseCtx, ViewError};
+
+/// Splits `arr` at `mid`, returning a [`ViewError`] rather than panicking if `arr` is too short.
+fn try_split_at_mut(arr: &mut [u8], mid: usize) -> Result<(&mut [u8], &mut [u8]), ViewError> {
+    if mid > arr.len() {
+        return Err(ViewError::new(mid, arr.len()));
+    }
+
+    Ok(arr.split_at_mut(mid))
+}
+
+/// Splits `arr` at `mid`, returning a [`ViewError`] rather than panicking if `arr` is too short.
+fn try_split_at(arr: &[u8], mid: usize) -> Result<(&[u8], &[u8]), ViewError> {
+    if mid > arr.len() {
+        return Err(ViewError::new(mid, arr.len()));
+    }
+
+    Ok(arr.split_at(mid))
+}
+
+/// Implemented by types which can be parsed from the start of a `&mut [u8]`.
+///
+/// Implemented by every fixed length `zordon` view and by the [`MutView`](crate::MutView) derive.
+pub trait TryMutView<'a>: Sized {
+    /// Returns the view and leftover slice, attaching the field path and absolute offset held by
+    /// `ctx` to any [`ViewError`].
+    fn try_mut_view_ctx(
+        arr: &'a mut [u8],
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError>;
+}
+
+/// Implemented by types which can be parsed from the start of a `&[u8]`.
+///
+/// Implemented by every fixed length read-only `zordon` view and by the
+/// [`MutView`](crate::MutView) derive.
+pub trait TryView<'a>: Sized {
+    /// Returns the view and leftover slice, attaching the field path and absolute offset held by
+    /// `ctx` to any [`ViewError`].
+    fn try_view_ctx(arr: &'a [u8], ctx: &ParseCtx) -> Result<(Self, &'a [u8]), ViewError>;
+}
+
+/// Names the same view type borrowing its bytes for `'b` instead.
+///
+/// Lets [`LazyViewArray`] hand out views of its elements which borrow the array, rather than
+/// consuming it. Implemented by the fixed length `zordon` views and by the
+/// [`MutView`](crate::MutView) derive.
+pub trait Reborrow<'b> {
+    /// The view type borrowing for `'b`.
+    type Target;
+}
+
+/// Implemented by views whose length is only known at runtime, such as [`VarArrayView`].
+///
+/// Used by the [`MutView`](crate::MutView) derive for fields with a `count` or `len` attribute.
+pub trait TryMutViewVar<'a>: Sized {
+    /// Returns a view of `count` elements and the leftover slice, attaching the field path and
+    /// absolute offset held by `ctx` to any [`ViewError`].
+    fn try_mut_view_count_ctx(
+        arr: &'a mut [u8],
+        count: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError>;
+
+    /// Returns a view filling the first `len` bytes of `arr` and the leftover slice, attaching the
+    /// field path and absolute offset held by `ctx` to any [`ViewError`].
+    ///
+    /// Returns a [`ViewError`] if the last element doesn't fit in `len` bytes.
+    fn try_mut_view_len_ctx(
+        arr: &'a mut [u8],
+        len: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError>;
+}
+
+/// Implemented by read-only views whose length is only known at runtime, such as
+/// [`VarArrayRef`].
+///
+/// Used by the [`MutView`](crate::MutView) derive for fields with a `count` or `len` attribute.
+pub trait TryViewVar<'a>: Sized {
+    /// Returns a view of `count` elements and the leftover slice, attaching the field path and
+    /// absolute offset held by `ctx` to any [`ViewError`].
+    fn try_view_count_ctx(
+        arr: &'a [u8],
+        count: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a [u8]), ViewError>;
+
+    /// Returns a view filling the first `len` bytes of `arr` and the leftover slice, attaching the
+    /// field path and absolute offset held by `ctx` to any [`ViewError`].
+    ///
+    /// Returns a [`ViewError`] if the last element doesn't fit in `len` bytes.
+    fn try_view_len_ctx(
+        arr: &'a [u8],
+        len: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a [u8]), ViewError>;
+}
+
+/// Implemented by tagged unions whose variant is chosen by a tag read before them, such as an
+/// enum using the [`MutView`](crate::MutView) derive.
+///
+/// Used by the [`MutView`](crate::MutView) derive for fields with a `tag` attribute.
+pub trait TryMutViewTagged<'a>: Sized {
+    /// Returns the variant selected by `tag` and the leftover slice, attaching the field path and
+    /// absolute offset held by `ctx` to any [`ViewError`].
+    ///
+    /// With a `len` the view takes exactly the first `len` bytes of `arr`, skipping any the
+    /// variant doesn't use. Without one a variant for unknown tags takes the rest of `arr`.
+    fn try_mut_view_tag_ctx(
+        arr: &'a mut [u8],
+        tag: usize,
+        len: Option<usize>,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError>;
+}
+
+/// Implemented by read-only tagged unions whose variant is chosen by a tag read before them.
+///
+/// Used by the [`MutView`](crate::MutView) derive for fields with a `tag` attribute.
+pub trait TryViewTagged<'a>: Sized {
+    /// Returns the variant selected by `tag` and the leftover slice, attaching the field path and
+    /// absolute offset held by `ctx` to any [`ViewError`].
+    ///
+    /// With a `len` the view takes exactly the first `len` bytes of `arr`, skipping any the
+    /// variant doesn't use. Without one a variant for unknown tags takes the rest of `arr`.
+    fn try_view_tag_ctx(
+        arr: &'a [u8],
+        tag: usize,
+        len: Option<usize>,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a [u8]), ViewError>;
+}
+
+/// Implemented by integer views whose value can be used as the `count` or `len` of a later field
+/// by the [`MutView`](crate::MutView) derive.
+pub trait LenValue {
+    /// Returns the value as a `usize`, or `None` if it is negative or too large.
+    fn len_value(&self) -> Option<usize>;
+}
+
+/// Implemented by views whose value can be checked against a constant by the `eq` and `magic`
+/// attributes of the [`MutView`](crate::MutView) derive.
+pub trait EqValue {
+    /// The type of the value, e.g. `[u8; 2]` for an `ArrayView<'a, [u8; 2]>`.
+    type Value: PartialEq + Into<FieldValue>;
+
+    /// Returns a copy of the viewed value.
+    fn eq_value(&self) -> Self::Value;
+
+    /// Returns a [`ViewErrorKind::UnexpectedValue`](crate::error::ViewErrorKind::UnexpectedValue)
+    /// error holding both values unless the viewed value equals `expected`.
+    fn check_eq(&self, expected: Self::Value) -> Result<(), ViewError> {
+        let found = self.eq_value();
+        if found == expected {
+            return Ok(());
+        }
+
+        Err(ViewError::unexpected_value(expected.into(), found.into()))
+    }
+}
+
+/// Implemented by views which can be marked `reserved` in the [`MutView`](crate::MutView) derive,
+/// which fails the parse unless they are zero.
+pub trait IsZero {
+    /// Returns true if every viewed bit is zero.
+    fn is_zero(&self) -> bool;
+}
+
+/// Implemented by views whose length is known at compile time, and by structs using the
+/// [`MutView`](crate::MutView) derive whose fields all have a fixed length and position.
+pub trait FixedSize {
+    /// Number of bytes viewed.
+    const SIZE: usize;
+}
+
+/// Implemented by mutable views whose reserved bits can be zeroed, and by structs using the
+/// [`MutView`](crate::MutView) derive with `reserved` fields.
+pub trait Sanitize {
+    /// Sets every reserved bit to zero.
+    fn sanitize(&mut self);
+}
+
+/// Returns the number of whole `size` byte elements in `len` bytes, or a [`ViewError`] for the
+/// trailing partial element.
+fn elem_count(len: usize, size: usize) -> Result<usize, ViewError> {
+    match len % size {
+        0 => Ok(len / size),
+        rem => Err(ViewError::new(size, rem).offset_by(len - rem)),
+    }
+}
+
 /// For getting/setting single byte values.
 pub trait ModByteView<'a, T> {
     /// Return a copy of the underlying value T.
@@ -24,13 +222,89 @@ pub trait ModMulByteView<'a, T, E> {
     fn set(&mut self, v: T);
 }
 
+/// For getting single byte values from a read-only view.
+pub trait RefByteView<'a, T> {
+    /// Return a copy of the underlying value T.
+    fn val(&self) -> T;
+}
+
+/// For getting multi byte values from a read-only view.
+pub trait RefMulByteView<'a, T, E> {
+    /// Return a copy of the underlying value T.
+    fn val(&self) -> T;
+}
+
 /// Marker type used with [`MulByteView`] as the E in MulByteView<'a, T, E> to specify a little endian view.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
 pub struct LitEnd;
 /// Marker type used with [`MulByteView`] as the E in MulByteView<'a, T, E> to specify a big endian view.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
 pub struct BigEnd;
 
+/// Marker type used with [`MulByteView`] as the E in MulByteView<'a, T, E> to specify the host's
+/// byte order.
+#[cfg(target_endian = "little")]
+pub type NatEnd = LitEnd;
+/// Marker type used with [`MulByteView`] as the E in MulByteView<'a, T, E> to specify the host's
+/// byte order.
+#[cfg(target_endian = "big")]
+pub type NatEnd = BigEnd;
+
+/// Marker type used with [`MulByteView`] as the E in MulByteView<'a, T, E> to specify a middle
+/// endian (word swapped) view.
+///
+/// 16-bit values are little endian. Wider values store their most significant half first, with
+/// each half little endian. This matches PDP-11 `u32`s (`0x0A0B0C0D` is stored as
+/// `0B 0A 0D 0C`) and ARM FPA doubles. See [`MiddleEndian`] for the supported types, which are the
+/// standard widths only: the odd width integers such as [`U24`] have no middle endian views.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct MidEnd;
+
+/// Implemented by the byte order markers whose order is fixed at compile time, which are the ones
+/// views can be constructed for without an [`Endian`].
+pub trait StaticEndian: Sized {
+    /// The marker value stored in a view, which is zero sized.
+    const MARKER: Self;
+}
+
+impl StaticEndian for LitEnd {
+    const MARKER: Self = LitEnd;
+}
+
+impl StaticEndian for BigEnd {
+    const MARKER: Self = BigEnd;
+}
+
+impl StaticEndian for MidEnd {
+    const MARKER: Self = MidEnd;
+}
+
+/// A byte order selected at runtime, used by [`DynEnd`] views.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Endian {
+    /// Little endian byte order.
+    Little,
+    /// Big endian byte order.
+    Big,
+}
+
+impl Endian {
+    /// The host's byte order.
+    #[cfg(target_endian = "little")]
+    pub const NATIVE: Endian = Endian::Little;
+    /// The host's byte order.
+    #[cfg(target_endian = "big")]
+    pub const NATIVE: Endian = Endian::Big;
+}
+
+/// Used with [`MulByteView`] as the E in MulByteView<'a, T, E> to specify a view whose byte order
+/// is decided at view time.
+///
+/// Views are constructed with `mut_view_endian`/`view_endian`, or by the [`MutView`](crate::MutView)
+/// derive from the [`Endian`] held by the [`ParseCtx`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct DynEnd(pub Endian);
+
 /// A mutable byte view for type T where the length of the view is always 1.
 ///
 /// Check [`ModByteView`] implementations for valid T monomorphisms.
@@ -57,6 +331,79 @@ impl<'a, T> ByteView<'a, T> {
             leftover,
         )
     }
+
+    /// Constructs a new [`ByteView`] and returns the leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `T.len() > arr.len()`.
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let (val, leftover) = try_split_at_mut(arr, core::mem::size_of::<T>())?;
+
+        Ok((
+            Self {
+                val,
+                _type: core::marker::PhantomData::<T>,
+            },
+            leftover,
+        ))
+    }
+}
+
+impl<'a, T> TryMutView<'a> for ByteView<'a, T> {
+    fn try_mut_view_ctx(
+        arr: &'a mut [u8],
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        Self::try_mut_view(arr).map_err(|e| ctx.error(e))
+    }
+}
+
+/// A read-only byte view for type T where the length of the view is always 1.
+///
+/// Check [`RefByteView`] implementations for valid T monomorphisms.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ByteRef<'a, T> {
+    val: &'a [u8],
+    _type: core::marker::PhantomData<T>,
+}
+
+impl<'a, T> ByteRef<'a, T> {
+    /// Constructs a new [`ByteRef`] and returns the leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T.len() > arr.len()`
+    pub fn view(arr: &'a [u8]) -> (Self, &'a [u8]) {
+        let (val, leftover) = arr.split_at(core::mem::size_of::<T>());
+
+        (
+            Self {
+                val,
+                _type: core::marker::PhantomData::<T>,
+            },
+            leftover,
+        )
+    }
+
+    /// Constructs a new [`ByteRef`] and returns the leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `T.len() > arr.len()`.
+    pub fn try_view(arr: &'a [u8]) -> Result<(Self, &'a [u8]), ViewError> {
+        let (val, leftover) = try_split_at(arr, core::mem::size_of::<T>())?;
+
+        Ok((
+            Self {
+                val,
+                _type: core::marker::PhantomData::<T>,
+            },
+            leftover,
+        ))
+    }
+}
+
+impl<'a, T> TryView<'a> for ByteRef<'a, T> {
+    fn try_view_ctx(arr: &'a [u8], ctx: &ParseCtx) -> Result<(Self, &'a [u8]), ViewError> {
+        Self::try_view(arr).map_err(|e| ctx.error(e))
+    }
 }
 
 /// Template for implementing ModByteView<'a, _>
@@ -78,17 +425,34 @@ macro_rules! impl_modbyteval {
 impl_modbyteval!(ByteView, u8);
 impl_modbyteval!(ByteView, i8);
 
+/// Template for implementing RefByteView<'a, _>
+#[macro_export]
+macro_rules! impl_refbyteval {
+    ($target:tt, $bytesized_type:tt) => {
+        impl<'a> RefByteView<'a, $bytesized_type> for $target<'a, $bytesized_type> {
+            fn val(&self) -> $bytesized_type {
+                self.val[0] as $bytesized_type
+            }
+        }
+    };
+}
+
+impl_refbyteval!(ByteRef, u8);
+impl_refbyteval!(ByteRef, i8);
+
 /// A mutable multi byte view for type T where the length of the view varies depending on T.
 ///
-/// For valid types for T, check [`ModByteView`] implementations.
+/// For valid types for T, check [`ModByteView`] implementations. [`DynEnd`] views are made with
+/// `mut_view_endian`.
 #[derive(Debug, PartialEq)]
 pub struct MulByteView<'a, T, E> {
     val: &'a mut [u8],
     _type: core::marker::PhantomData<T>,
-    _endian: core::marker::PhantomData<E>,
+    /// Byte order marker, zero sized except for [`DynEnd`] which holds the runtime order.
+    endian: E,
 }
 
-impl<'a, T, E> MulByteView<'a, T, E> {
+impl<'a, T, E: StaticEndian> MulByteView<'a, T, E> {
     /// Returns a [`MulByteView`] and leftover slice.
     ///
     /// # Panics
@@ -101,11 +465,196 @@ impl<'a, T, E> MulByteView<'a, T, E> {
             Self {
                 val,
                 _type: core::marker::PhantomData::<T>,
-                _endian: core::marker::PhantomData::<E>,
+                endian: E::MARKER,
+            },
+            leftover,
+        )
+    }
+
+    /// Returns a [`MulByteView`] and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `T.len() > arr.len()`.
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let (val, leftover) = try_split_at_mut(arr, core::mem::size_of::<T>())?;
+
+        Ok((
+            Self {
+                val,
+                _type: core::marker::PhantomData::<T>,
+                endian: E::MARKER,
+            },
+            leftover,
+        ))
+    }
+}
+
+impl<'a, T, E: StaticEndian> TryMutView<'a> for MulByteView<'a, T, E> {
+    fn try_mut_view_ctx(
+        arr: &'a mut [u8],
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        Self::try_mut_view(arr).map_err(|e| ctx.error(e))
+    }
+}
+
+impl<'a, T> MulByteView<'a, T, DynEnd> {
+    /// Returns a [`MulByteView`] with a runtime byte order and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T.len() > arr.len()`
+    pub fn mut_view_endian(arr: &'a mut [u8], endian: Endian) -> (Self, &'a mut [u8]) {
+        let (val, leftover) = arr.split_at_mut(core::mem::size_of::<T>());
+
+        (
+            Self {
+                val,
+                _type: core::marker::PhantomData::<T>,
+                endian: DynEnd(endian),
+            },
+            leftover,
+        )
+    }
+
+    /// Returns a [`MulByteView`] with a runtime byte order and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `T.len() > arr.len()`.
+    pub fn try_mut_view_endian(
+        arr: &'a mut [u8],
+        endian: Endian,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let (val, leftover) = try_split_at_mut(arr, core::mem::size_of::<T>())?;
+
+        Ok((
+            Self {
+                val,
+                _type: core::marker::PhantomData::<T>,
+                endian: DynEnd(endian),
+            },
+            leftover,
+        ))
+    }
+
+    /// Returns the byte order of the view.
+    pub fn endian(&self) -> Endian {
+        self.endian.0
+    }
+}
+
+impl<'a, T> TryMutView<'a> for MulByteView<'a, T, DynEnd> {
+    /// Uses the [`Endian`] held by `ctx`, returning a [`ViewError`] if there is none.
+    fn try_mut_view_ctx(
+        arr: &'a mut [u8],
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let endian = ctx.endian().ok_or_else(|| ctx.error(ViewError::missing_endian()))?;
+
+        Self::try_mut_view_endian(arr, endian).map_err(|e| ctx.error(e))
+    }
+}
+
+/// A read-only multi byte view for type T where the length of the view varies depending on T.
+///
+/// For valid types for T, check [`RefMulByteView`] implementations. [`DynEnd`] views are made with
+/// `view_endian`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct MulByteRef<'a, T, E> {
+    val: &'a [u8],
+    _type: core::marker::PhantomData<T>,
+    /// Byte order marker, zero sized except for [`DynEnd`] which holds the runtime order.
+    endian: E,
+}
+
+impl<'a, T, E: StaticEndian> MulByteRef<'a, T, E> {
+    /// Returns a [`MulByteRef`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T.len() > arr.len()`
+    pub fn view(arr: &'a [u8]) -> (Self, &'a [u8]) {
+        let (val, leftover) = arr.split_at(core::mem::size_of::<T>());
+
+        (
+            Self {
+                val,
+                _type: core::marker::PhantomData::<T>,
+                endian: E::MARKER,
+            },
+            leftover,
+        )
+    }
+
+    /// Returns a [`MulByteRef`] and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `T.len() > arr.len()`.
+    pub fn try_view(arr: &'a [u8]) -> Result<(Self, &'a [u8]), ViewError> {
+        let (val, leftover) = try_split_at(arr, core::mem::size_of::<T>())?;
+
+        Ok((
+            Self {
+                val,
+                _type: core::marker::PhantomData::<T>,
+                endian: E::MARKER,
+            },
+            leftover,
+        ))
+    }
+}
+
+impl<'a, T, E: StaticEndian> TryView<'a> for MulByteRef<'a, T, E> {
+    fn try_view_ctx(arr: &'a [u8], ctx: &ParseCtx) -> Result<(Self, &'a [u8]), ViewError> {
+        Self::try_view(arr).map_err(|e| ctx.error(e))
+    }
+}
+
+impl<'a, T> MulByteRef<'a, T, DynEnd> {
+    /// Returns a [`MulByteRef`] with a runtime byte order and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T.len() > arr.len()`
+    pub fn view_endian(arr: &'a [u8], endian: Endian) -> (Self, &'a [u8]) {
+        let (val, leftover) = arr.split_at(core::mem::size_of::<T>());
+
+        (
+            Self {
+                val,
+                _type: core::marker::PhantomData::<T>,
+                endian: DynEnd(endian),
             },
             leftover,
         )
     }
+
+    /// Returns a [`MulByteRef`] with a runtime byte order and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `T.len() > arr.len()`.
+    pub fn try_view_endian(arr: &'a [u8], endian: Endian) -> Result<(Self, &'a [u8]), ViewError> {
+        let (val, leftover) = try_split_at(arr, core::mem::size_of::<T>())?;
+
+        Ok((
+            Self {
+                val,
+                _type: core::marker::PhantomData::<T>,
+                endian: DynEnd(endian),
+            },
+            leftover,
+        ))
+    }
+
+    /// Returns the byte order of the view.
+    pub fn endian(&self) -> Endian {
+        self.endian.0
+    }
+}
+
+impl<'a, T> TryView<'a> for MulByteRef<'a, T, DynEnd> {
+    /// Uses the [`Endian`] held by `ctx`, returning a [`ViewError`] if there is none.
+    fn try_view_ctx(arr: &'a [u8], ctx: &ParseCtx) -> Result<(Self, &'a [u8]), ViewError> {
+        let endian = ctx.endian().ok_or_else(|| ctx.error(ViewError::missing_endian()))?;
+
+        Self::try_view_endian(arr, endian).map_err(|e| ctx.error(e))
+    }
 }
 
 /// Template for implementing ModMulByteView<'a, _, _>.
@@ -156,21 +705,645 @@ impl_modmulbyteval!(
 );
 impl_modmulbyteval!(MulByteView, i128, BigEnd, BigEndian, read_i128, write_i128);
 
-/// Template for implementing oper assign overloading
+// Floats are read/written via their bit pattern, so NaN payloads round-trip bit-exactly.
+impl_modmulbyteval!(MulByteView, f32, LitEnd, LittleEndian, read_f32, write_f32);
+impl_modmulbyteval!(MulByteView, f32, BigEnd, BigEndian, read_f32, write_f32);
+impl_modmulbyteval!(MulByteView, f64, LitEnd, LittleEndian, read_f64, write_f64);
+impl_modmulbyteval!(MulByteView, f64, BigEnd, BigEndian, read_f64, write_f64);
+
+/// Template for implementing RefMulByteView<'a, _, _>.
 #[macro_export]
-macro_rules! impl_oper_assign_overload {
-    ($oper_name:ident, $bound:ident, $fname:ident, $oper:tt, $type:tt, $endian:tt) => {
-        impl<'a, $type, $endian> $oper_name<$type> for MulByteView<'a, $type, $endian>
-        where
-            MulByteView<'a, $type, $endian>: ModMulByteView<'a, $type, $endian>,
-            $type: $bound + $bound<Output = $type>,
-        {
-            fn $fname(&mut self, rhs: $type) {
-                self.set(self.val() $oper rhs)
+macro_rules! impl_refmulbyteval {
+    ($target:tt, $type:tt, $endian:tt, $endianident:ident, $read:ident) => {
+        impl<'a> RefMulByteView<'a, $type, $endian> for $target<'a, $type, $endian> {
+            fn val(&self) -> $type {
+                $endianident::$read(self.val)
             }
         }
+    };
+}
 
-        impl<'a, $type> $oper_name<$type> for ByteView<'a, $type>
+impl_refmulbyteval!(MulByteRef, u16, LitEnd, LittleEndian, read_u16);
+impl_refmulbyteval!(MulByteRef, u16, BigEnd, BigEndian, read_u16);
+impl_refmulbyteval!(MulByteRef, u32, LitEnd, LittleEndian, read_u32);
+impl_refmulbyteval!(MulByteRef, u32, BigEnd, BigEndian, read_u32);
+impl_refmulbyteval!(MulByteRef, u64, LitEnd, LittleEndian, read_u64);
+impl_refmulbyteval!(MulByteRef, u64, BigEnd, BigEndian, read_u64);
+impl_refmulbyteval!(MulByteRef, u128, LitEnd, LittleEndian, read_u128);
+impl_refmulbyteval!(MulByteRef, u128, BigEnd, BigEndian, read_u128);
+
+impl_refmulbyteval!(MulByteRef, i16, LitEnd, LittleEndian, read_i16);
+impl_refmulbyteval!(MulByteRef, i16, BigEnd, BigEndian, read_i16);
+impl_refmulbyteval!(MulByteRef, i32, LitEnd, LittleEndian, read_i32);
+impl_refmulbyteval!(MulByteRef, i32, BigEnd, BigEndian, read_i32);
+impl_refmulbyteval!(MulByteRef, i64, LitEnd, LittleEndian, read_i64);
+impl_refmulbyteval!(MulByteRef, i64, BigEnd, BigEndian, read_i64);
+impl_refmulbyteval!(MulByteRef, i128, LitEnd, LittleEndian, read_i128);
+impl_refmulbyteval!(MulByteRef, i128, BigEnd, BigEndian, read_i128);
+
+impl_refmulbyteval!(MulByteRef, f32, LitEnd, LittleEndian, read_f32);
+impl_refmulbyteval!(MulByteRef, f32, BigEnd, BigEndian, read_f32);
+impl_refmulbyteval!(MulByteRef, f64, LitEnd, LittleEndian, read_f64);
+impl_refmulbyteval!(MulByteRef, f64, BigEnd, BigEndian, read_f64);
+
+/// Read/write functions for [`MidEnd`] views, mirroring the `byteorder` functions used by
+/// [`impl_modmulbyteval`].
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct MiddleEndian;
+
+/// Template for implementing word swapped read/write functions on [`MiddleEndian`].
+macro_rules! impl_middle_endian {
+    ($read:ident, $write:ident, $type:tt, $unsigned:tt, $read_le:ident, $write_le:ident) => {
+        impl MiddleEndian {
+            #[doc = concat!("Reads a middle endian `", stringify!($type), "`.")]
+            pub fn $read(buf: &[u8]) -> $type {
+                let v = LittleEndian::$read_le(buf);
+
+                v.rotate_left($unsigned::BITS / 2) as $type
+            }
+
+            #[doc = concat!("Writes a middle endian `", stringify!($type), "`.")]
+            pub fn $write(buf: &mut [u8], v: $type) {
+                LittleEndian::$write_le(buf, (v as $unsigned).rotate_left($unsigned::BITS / 2))
+            }
+        }
+    };
+}
+
+impl_middle_endian!(read_u32, write_u32, u32, u32, read_u32, write_u32);
+impl_middle_endian!(read_u64, write_u64, u64, u64, read_u64, write_u64);
+impl_middle_endian!(read_u128, write_u128, u128, u128, read_u128, write_u128);
+impl_middle_endian!(read_i32, write_i32, i32, u32, read_u32, write_u32);
+impl_middle_endian!(read_i64, write_i64, i64, u64, read_u64, write_u64);
+impl_middle_endian!(read_i128, write_i128, i128, u128, read_u128, write_u128);
+
+impl MiddleEndian {
+    /// Reads a middle endian `u16`, which is the same as little endian.
+    pub fn read_u16(buf: &[u8]) -> u16 {
+        LittleEndian::read_u16(buf)
+    }
+
+    /// Writes a middle endian `u16`, which is the same as little endian.
+    pub fn write_u16(buf: &mut [u8], v: u16) {
+        LittleEndian::write_u16(buf, v)
+    }
+
+    /// Reads a middle endian `i16`, which is the same as little endian.
+    pub fn read_i16(buf: &[u8]) -> i16 {
+        LittleEndian::read_i16(buf)
+    }
+
+    /// Writes a middle endian `i16`, which is the same as little endian.
+    pub fn write_i16(buf: &mut [u8], v: i16) {
+        LittleEndian::write_i16(buf, v)
+    }
+
+    /// Reads a middle endian `f32`.
+    pub fn read_f32(buf: &[u8]) -> f32 {
+        f32::from_bits(Self::read_u32(buf))
+    }
+
+    /// Writes a middle endian `f32`.
+    pub fn write_f32(buf: &mut [u8], v: f32) {
+        Self::write_u32(buf, v.to_bits())
+    }
+
+    /// Reads a middle endian `f64`.
+    pub fn read_f64(buf: &[u8]) -> f64 {
+        f64::from_bits(Self::read_u64(buf))
+    }
+
+    /// Writes a middle endian `f64`.
+    pub fn write_f64(buf: &mut [u8], v: f64) {
+        Self::write_u64(buf, v.to_bits())
+    }
+}
+
+impl_modmulbyteval!(MulByteView, u16, MidEnd, MiddleEndian, read_u16, write_u16);
+impl_modmulbyteval!(MulByteView, u32, MidEnd, MiddleEndian, read_u32, write_u32);
+impl_modmulbyteval!(MulByteView, u64, MidEnd, MiddleEndian, read_u64, write_u64);
+impl_modmulbyteval!(MulByteView, u128, MidEnd, MiddleEndian, read_u128, write_u128);
+impl_modmulbyteval!(MulByteView, i16, MidEnd, MiddleEndian, read_i16, write_i16);
+impl_modmulbyteval!(MulByteView, i32, MidEnd, MiddleEndian, read_i32, write_i32);
+impl_modmulbyteval!(MulByteView, i64, MidEnd, MiddleEndian, read_i64, write_i64);
+impl_modmulbyteval!(MulByteView, i128, MidEnd, MiddleEndian, read_i128, write_i128);
+impl_modmulbyteval!(MulByteView, f32, MidEnd, MiddleEndian, read_f32, write_f32);
+impl_modmulbyteval!(MulByteView, f64, MidEnd, MiddleEndian, read_f64, write_f64);
+
+impl_refmulbyteval!(MulByteRef, u16, MidEnd, MiddleEndian, read_u16);
+impl_refmulbyteval!(MulByteRef, u32, MidEnd, MiddleEndian, read_u32);
+impl_refmulbyteval!(MulByteRef, u64, MidEnd, MiddleEndian, read_u64);
+impl_refmulbyteval!(MulByteRef, u128, MidEnd, MiddleEndian, read_u128);
+impl_refmulbyteval!(MulByteRef, i16, MidEnd, MiddleEndian, read_i16);
+impl_refmulbyteval!(MulByteRef, i32, MidEnd, MiddleEndian, read_i32);
+impl_refmulbyteval!(MulByteRef, i64, MidEnd, MiddleEndian, read_i64);
+impl_refmulbyteval!(MulByteRef, i128, MidEnd, MiddleEndian, read_i128);
+impl_refmulbyteval!(MulByteRef, f32, MidEnd, MiddleEndian, read_f32);
+impl_refmulbyteval!(MulByteRef, f64, MidEnd, MiddleEndian, read_f64);
+
+/// Template for implementing ModMulByteView<'a, _, _> and RefMulByteView<'a, _, _> for single
+/// byte values, which read the same in every byte order.
+///
+/// These allow generic views such as [`BitView`] to be used with byte sized words.
+macro_rules! impl_mulbyteval_byte {
+    ($type:tt, $endian:tt) => {
+        impl<'a> ModMulByteView<'a, $type, $endian> for MulByteView<'a, $type, $endian> {
+            fn val(&self) -> $type {
+                self.val[0] as $type
+            }
+
+            fn set(&mut self, v: $type) {
+                self.val[0] = v as u8
+            }
+        }
+
+        impl<'a> RefMulByteView<'a, $type, $endian> for MulByteRef<'a, $type, $endian> {
+            fn val(&self) -> $type {
+                self.val[0] as $type
+            }
+        }
+    };
+}
+
+impl_mulbyteval_byte!(u8, LitEnd);
+impl_mulbyteval_byte!(u8, BigEnd);
+impl_mulbyteval_byte!(u8, MidEnd);
+impl_mulbyteval_byte!(i8, LitEnd);
+impl_mulbyteval_byte!(i8, BigEnd);
+impl_mulbyteval_byte!(i8, MidEnd);
+
+/// IEEE 754 binary16 (half precision) float, stored as its bit pattern.
+///
+/// Used as the T in `MulByteView<'a, F16, E>`, whose `val`/`set` convert to and from [`f32`].
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct F16(u16);
+
+impl F16 {
+    /// Constructs a [`F16`] from its raw bit pattern.
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the raw bit pattern.
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Converts an [`f32`] to the nearest [`F16`], rounding ties to even.
+    ///
+    /// Values too large for binary16 become infinity. NaNs stay NaN and keep the top bits of
+    /// their payload.
+    pub fn from_f32(v: f32) -> Self {
+        let x = v.to_bits();
+        let sign = ((x >> 16) & 0x8000) as u16;
+        let exp = ((x >> 23) & 0xFF) as i32;
+        let man = x & 0x7F_FFFF;
+
+        if exp == 0xFF {
+            let nan = if man == 0 { 0 } else { 0x0200 | (man >> 13) as u16 };
+            return Self(sign | 0x7C00 | nan);
+        }
+
+        let e = exp - 127 + 15;
+        if e >= 0x1F {
+            return Self(sign | 0x7C00);
+        }
+
+        if e <= 0 {
+            // Subnormal in binary16, shift the explicit mantissa down to a multiple of 2^-24.
+            let shift = (14 - e) as u32;
+            if shift > 24 {
+                return Self(sign);
+            }
+
+            return Self(sign | round_shr(man | 0x80_0000, shift) as u16);
+        }
+
+        // A carry out of the mantissa correctly bumps the exponent, up to infinity.
+        let bits = ((e as u32) << 10) | (man >> 13);
+        Self(sign | (bits + round_bit(man, 13)) as u16)
+    }
+
+    /// Converts to an [`f32`]. Every binary16 value is exactly representable.
+    pub fn to_f32(self) -> f32 {
+        let h = self.0 as u32;
+        let sign = (h & 0x8000) << 16;
+        let exp = (h >> 10) & 0x1F;
+        let man = h & 0x3FF;
+
+        match exp {
+            0 => {
+                let v = man as f32 / (1 << 24) as f32;
+                if sign == 0 {
+                    v
+                } else {
+                    -v
+                }
+            }
+            0x1F => f32::from_bits(sign | 0x7F80_0000 | (man << 13)),
+            _ => f32::from_bits(sign | ((exp + 127 - 15) << 23) | (man << 13)),
+        }
+    }
+}
+
+/// bfloat16 float, stored as its bit pattern.
+///
+/// Used as the T in `MulByteView<'a, BF16, E>`, whose `val`/`set` convert to and from [`f32`].
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct BF16(u16);
+
+impl BF16 {
+    /// Constructs a [`BF16`] from its raw bit pattern.
+    pub const fn from_bits(bits: u16) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the raw bit pattern.
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    /// Converts an [`f32`] to the nearest [`BF16`], rounding ties to even.
+    ///
+    /// NaNs stay NaN and keep the top bits of their payload.
+    pub fn from_f32(v: f32) -> Self {
+        let x = v.to_bits();
+
+        if v.is_nan() {
+            return Self((x >> 16) as u16 | 0x0040);
+        }
+
+        Self((x >> 16) as u16 + round_bit(x, 16) as u16)
+    }
+
+    /// Converts to an [`f32`]. Every bfloat16 value is exactly representable.
+    pub fn to_f32(self) -> f32 {
+        f32::from_bits((self.0 as u32) << 16)
+    }
+}
+
+/// Returns 1 if the `shift` low bits of `v` should round `v >> shift` up, rounding ties to even.
+fn round_bit(v: u32, shift: u32) -> u32 {
+    let rem = v & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+
+    (rem > half || (rem == half && (v >> shift) & 1 == 1)) as u32
+}
+
+/// Shifts `v` right by `shift`, rounding ties to even.
+fn round_shr(v: u32, shift: u32) -> u32 {
+    (v >> shift) + round_bit(v, shift)
+}
+
+/// Template for implementing ModMulByteView<'a, f32, _> for half precision float views.
+#[macro_export]
+macro_rules! impl_modmulbyteval_half {
+    ($target:tt, $type:tt, $endian:tt, $endianident:ident) => {
+        impl<'a> ModMulByteView<'a, f32, $endian> for $target<'a, $type, $endian> {
+            fn val(&self) -> f32 {
+                $type::from_bits($endianident::read_u16(self.val)).to_f32()
+            }
+
+            fn set(&mut self, v: f32) {
+                $endianident::write_u16(self.val, $type::from_f32(v).to_bits())
+            }
+        }
+    };
+}
+
+impl_modmulbyteval_half!(MulByteView, F16, LitEnd, LittleEndian);
+impl_modmulbyteval_half!(MulByteView, F16, BigEnd, BigEndian);
+impl_modmulbyteval_half!(MulByteView, BF16, LitEnd, LittleEndian);
+impl_modmulbyteval_half!(MulByteView, BF16, BigEnd, BigEndian);
+
+/// Template for implementing RefMulByteView<'a, f32, _> for half precision float views.
+#[macro_export]
+macro_rules! impl_refmulbyteval_half {
+    ($target:tt, $type:tt, $endian:tt, $endianident:ident) => {
+        impl<'a> RefMulByteView<'a, f32, $endian> for $target<'a, $type, $endian> {
+            fn val(&self) -> f32 {
+                $type::from_bits($endianident::read_u16(self.val)).to_f32()
+            }
+        }
+    };
+}
+
+impl_refmulbyteval_half!(MulByteRef, F16, LitEnd, LittleEndian);
+impl_refmulbyteval_half!(MulByteRef, F16, BigEnd, BigEndian);
+impl_refmulbyteval_half!(MulByteRef, BF16, LitEnd, LittleEndian);
+impl_refmulbyteval_half!(MulByteRef, BF16, BigEnd, BigEndian);
+
+impl_modmulbyteval_half!(MulByteView, F16, MidEnd, MiddleEndian);
+impl_modmulbyteval_half!(MulByteView, BF16, MidEnd, MiddleEndian);
+impl_refmulbyteval_half!(MulByteRef, F16, MidEnd, MiddleEndian);
+impl_refmulbyteval_half!(MulByteRef, BF16, MidEnd, MiddleEndian);
+
+/// Template for implementing odd width integer marker types.
+#[macro_export]
+macro_rules! impl_odd_int {
+    (unsigned $type:ident, $repr:tt, $bytes:expr, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// Used as the T in `MulByteView<'a, T, E>`, whose `val`/`set` use the next largest primitive.
+        #[repr(transparent)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+        pub struct $type([u8; $bytes]);
+
+        impl $type {
+            /// Number of bytes in the view.
+            pub const BYTES: usize = $bytes;
+            /// Smallest value the view can hold.
+            pub const MIN: $repr = 0;
+            /// Largest value the view can hold.
+            pub const MAX: $repr = ((1u64 << ($bytes * 8)) - 1) as $repr;
+
+            fn from_raw(raw: u64) -> $repr {
+                raw as $repr
+            }
+
+            fn to_raw(v: $repr) -> u64 {
+                v as u64 & Self::MAX as u64
+            }
+        }
+    };
+    (signed $type:ident, $repr:tt, $bytes:expr, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// Used as the T in `MulByteView<'a, T, E>`, whose `val`/`set` use the next largest primitive.
+        /// Reads are sign extended.
+        #[repr(transparent)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+        pub struct $type([u8; $bytes]);
+
+        impl $type {
+            /// Number of bytes in the view.
+            pub const BYTES: usize = $bytes;
+            /// Smallest value the view can hold.
+            pub const MIN: $repr = -(1 << ($bytes * 8 - 1));
+            /// Largest value the view can hold.
+            pub const MAX: $repr = (1 << ($bytes * 8 - 1)) - 1;
+
+            fn from_raw(raw: u64) -> $repr {
+                let unused = 64 - $bytes * 8;
+
+                ((raw << unused) as i64 >> unused) as $repr
+            }
+
+            fn to_raw(v: $repr) -> u64 {
+                v as u64 & ((1u64 << ($bytes * 8)) - 1)
+            }
+        }
+    };
+}
+
+impl_odd_int!(unsigned U24, u32, 3, "Marker type for a 24 bit unsigned integer.");
+impl_odd_int!(unsigned U40, u64, 5, "Marker type for a 40 bit unsigned integer.");
+impl_odd_int!(unsigned U48, u64, 6, "Marker type for a 48 bit unsigned integer.");
+impl_odd_int!(unsigned U56, u64, 7, "Marker type for a 56 bit unsigned integer.");
+impl_odd_int!(signed I24, i32, 3, "Marker type for a 24 bit signed integer.");
+impl_odd_int!(signed I40, i64, 5, "Marker type for a 40 bit signed integer.");
+impl_odd_int!(signed I48, i64, 6, "Marker type for a 48 bit signed integer.");
+impl_odd_int!(signed I56, i64, 7, "Marker type for a 56 bit signed integer.");
+
+/// Template for implementing ModMulByteView<'a, _, _> for odd width integer views.
+///
+/// `set` keeps the low bits of values which do not fit.
+#[macro_export]
+macro_rules! impl_modmulbyteval_odd {
+    ($target:tt, $type:tt, $repr:tt, $endian:tt, $endianident:ident) => {
+        impl<'a> ModMulByteView<'a, $repr, $endian> for $target<'a, $type, $endian> {
+            fn val(&self) -> $repr {
+                $type::from_raw($endianident::read_uint(self.val, $type::BYTES))
+            }
+
+            fn set(&mut self, v: $repr) {
+                $endianident::write_uint(self.val, $type::to_raw(v), $type::BYTES)
+            }
+        }
+    };
+}
+
+/// Template for implementing `try_set` on odd width integer views of any byte order.
+///
+/// Unlike `set`, `try_set` rejects values which do not fit.
+#[macro_export]
+macro_rules! impl_try_set_odd {
+    ($target:tt, $type:tt, $repr:tt) => {
+        impl<'a, E> $target<'a, $type, E>
+        where
+            Self: ModMulByteView<'a, $repr, E>,
+        {
+            /// Sets the underlying value, returning an [`OutOfRange`] error if it does not fit.
+            pub fn try_set(&mut self, v: $repr) -> Result<(), OutOfRange> {
+                if !($type::MIN..=$type::MAX).contains(&v) {
+                    return Err(OutOfRange {
+                        value: v as i128,
+                        min: $type::MIN as i128,
+                        max: $type::MAX as i128,
+                    });
+                }
+
+                self.set(v);
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_modmulbyteval_odd!(MulByteView, U24, u32, LitEnd, LittleEndian);
+impl_modmulbyteval_odd!(MulByteView, U24, u32, BigEnd, BigEndian);
+impl_modmulbyteval_odd!(MulByteView, U40, u64, LitEnd, LittleEndian);
+impl_modmulbyteval_odd!(MulByteView, U40, u64, BigEnd, BigEndian);
+impl_modmulbyteval_odd!(MulByteView, U48, u64, LitEnd, LittleEndian);
+impl_modmulbyteval_odd!(MulByteView, U48, u64, BigEnd, BigEndian);
+impl_modmulbyteval_odd!(MulByteView, U56, u64, LitEnd, LittleEndian);
+impl_modmulbyteval_odd!(MulByteView, U56, u64, BigEnd, BigEndian);
+
+impl_modmulbyteval_odd!(MulByteView, I24, i32, LitEnd, LittleEndian);
+impl_modmulbyteval_odd!(MulByteView, I24, i32, BigEnd, BigEndian);
+impl_modmulbyteval_odd!(MulByteView, I40, i64, LitEnd, LittleEndian);
+impl_modmulbyteval_odd!(MulByteView, I40, i64, BigEnd, BigEndian);
+impl_modmulbyteval_odd!(MulByteView, I48, i64, LitEnd, LittleEndian);
+impl_modmulbyteval_odd!(MulByteView, I48, i64, BigEnd, BigEndian);
+impl_modmulbyteval_odd!(MulByteView, I56, i64, LitEnd, LittleEndian);
+impl_modmulbyteval_odd!(MulByteView, I56, i64, BigEnd, BigEndian);
+
+impl_try_set_odd!(MulByteView, U24, u32);
+impl_try_set_odd!(MulByteView, U40, u64);
+impl_try_set_odd!(MulByteView, U48, u64);
+impl_try_set_odd!(MulByteView, U56, u64);
+impl_try_set_odd!(MulByteView, I24, i32);
+impl_try_set_odd!(MulByteView, I40, i64);
+impl_try_set_odd!(MulByteView, I48, i64);
+impl_try_set_odd!(MulByteView, I56, i64);
+
+/// Template for implementing RefMulByteView<'a, _, _> for odd width integer views.
+#[macro_export]
+macro_rules! impl_refmulbyteval_odd {
+    ($target:tt, $type:tt, $repr:tt, $endian:tt, $endianident:ident) => {
+        impl<'a> RefMulByteView<'a, $repr, $endian> for $target<'a, $type, $endian> {
+            fn val(&self) -> $repr {
+                $type::from_raw($endianident::read_uint(self.val, $type::BYTES))
+            }
+        }
+    };
+}
+
+impl_refmulbyteval_odd!(MulByteRef, U24, u32, LitEnd, LittleEndian);
+impl_refmulbyteval_odd!(MulByteRef, U24, u32, BigEnd, BigEndian);
+impl_refmulbyteval_odd!(MulByteRef, U40, u64, LitEnd, LittleEndian);
+impl_refmulbyteval_odd!(MulByteRef, U40, u64, BigEnd, BigEndian);
+impl_refmulbyteval_odd!(MulByteRef, U48, u64, LitEnd, LittleEndian);
+impl_refmulbyteval_odd!(MulByteRef, U48, u64, BigEnd, BigEndian);
+impl_refmulbyteval_odd!(MulByteRef, U56, u64, LitEnd, LittleEndian);
+impl_refmulbyteval_odd!(MulByteRef, U56, u64, BigEnd, BigEndian);
+
+impl_refmulbyteval_odd!(MulByteRef, I24, i32, LitEnd, LittleEndian);
+impl_refmulbyteval_odd!(MulByteRef, I24, i32, BigEnd, BigEndian);
+impl_refmulbyteval_odd!(MulByteRef, I40, i64, LitEnd, LittleEndian);
+impl_refmulbyteval_odd!(MulByteRef, I40, i64, BigEnd, BigEndian);
+impl_refmulbyteval_odd!(MulByteRef, I48, i64, LitEnd, LittleEndian);
+impl_refmulbyteval_odd!(MulByteRef, I48, i64, BigEnd, BigEndian);
+impl_refmulbyteval_odd!(MulByteRef, I56, i64, LitEnd, LittleEndian);
+impl_refmulbyteval_odd!(MulByteRef, I56, i64, BigEnd, BigEndian);
+
+// DynEnd views delegate to the LitEnd/BigEnd implementations, so every type with both of those
+// also supports a runtime byte order.
+impl<'a, T, V> ModMulByteView<'a, V, DynEnd> for MulByteView<'a, T, DynEnd>
+where
+    for<'b> MulByteRef<'b, T, LitEnd>: RefMulByteView<'b, V, LitEnd>,
+    for<'b> MulByteRef<'b, T, BigEnd>: RefMulByteView<'b, V, BigEnd>,
+    for<'b> MulByteView<'b, T, LitEnd>: ModMulByteView<'b, V, LitEnd>,
+    for<'b> MulByteView<'b, T, BigEnd>: ModMulByteView<'b, V, BigEnd>,
+{
+    fn val(&self) -> V {
+        match self.endian.0 {
+            Endian::Little => MulByteRef::<T, LitEnd>::view(self.val).0.val(),
+            Endian::Big => MulByteRef::<T, BigEnd>::view(self.val).0.val(),
+        }
+    }
+
+    fn set(&mut self, v: V) {
+        match self.endian.0 {
+            Endian::Little => MulByteView::<T, LitEnd>::mut_view(self.val).0.set(v),
+            Endian::Big => MulByteView::<T, BigEnd>::mut_view(self.val).0.set(v),
+        }
+    }
+}
+
+impl<'a, T, V> RefMulByteView<'a, V, DynEnd> for MulByteRef<'a, T, DynEnd>
+where
+    for<'b> MulByteRef<'b, T, LitEnd>: RefMulByteView<'b, V, LitEnd>,
+    for<'b> MulByteRef<'b, T, BigEnd>: RefMulByteView<'b, V, BigEnd>,
+{
+    fn val(&self) -> V {
+        match self.endian.0 {
+            Endian::Little => MulByteRef::<T, LitEnd>::view(self.val).0.val(),
+            Endian::Big => MulByteRef::<T, BigEnd>::view(self.val).0.val(),
+        }
+    }
+}
+
+/// Template for implementing LenValue for integer views.
+macro_rules! impl_len_value {
+    ($target:ident, $trait:ident, [$($gen:tt)*], [$($args:tt)*]) => {
+        impl<'a, T, $($gen)*> LenValue for $target<'a, T, $($args)*>
+        where
+            $target<'a, T, $($args)*>: $trait<'a, T, $($args)*>,
+            T: TryInto<usize>,
+        {
+            fn len_value(&self) -> Option<usize> {
+                self.val().try_into().ok()
+            }
+        }
+
+        impl<'a, T, $($gen)*> EqValue for $target<'a, T, $($args)*>
+        where
+            $target<'a, T, $($args)*>: $trait<'a, T, $($args)*>,
+            T: PartialEq + Into<FieldValue>,
+        {
+            type Value = T;
+
+            fn eq_value(&self) -> T {
+                self.val()
+            }
+        }
+    };
+}
+
+impl_len_value!(ByteView, ModByteView, [], []);
+impl_len_value!(ByteRef, RefByteView, [], []);
+impl_len_value!(MulByteView, ModMulByteView, [E], [E]);
+impl_len_value!(MulByteRef, RefMulByteView, [E], [E]);
+
+/// Template for implementing IsZero, and Sanitize for mutable views, on views of a byte slice.
+macro_rules! impl_reserved {
+    ($target:ident, $buf:ident, [$($gen:tt)*], [$($args:tt)*]) => {
+        impl<'a, $($gen)*> IsZero for $target<'a, $($args)*> {
+            fn is_zero(&self) -> bool {
+                self.$buf.iter().all(|b| *b == 0)
+            }
+        }
+    };
+    (mut $target:ident, $buf:ident, [$($gen:tt)*], [$($args:tt)*]) => {
+        impl_reserved!($target, $buf, [$($gen)*], [$($args)*]);
+
+        impl<'a, $($gen)*> Sanitize for $target<'a, $($args)*> {
+            fn sanitize(&mut self) {
+                self.$buf.fill(0)
+            }
+        }
+    };
+}
+
+impl_reserved!(mut ByteView, val, [T], [T]);
+impl_reserved!(ByteRef, val, [T], [T]);
+impl_reserved!(mut MulByteView, val, [T, E], [T, E]);
+impl_reserved!(MulByteRef, val, [T, E], [T, E]);
+impl_reserved!(mut ArrayView, buf, [T], [T]);
+impl_reserved!(ArrayRef, buf, [T], [T]);
+impl_reserved!(mut VarArrayView, buf, [T], [T]);
+impl_reserved!(VarArrayRef, buf, [T], [T]);
+impl_reserved!(mut MulArrayView, buf, [T, E], [T, E]);
+impl_reserved!(mut VarMulArrayView, buf, [T, E], [T, E]);
+impl_reserved!(MulArrayRef, buf, [T, E], [T, E]);
+impl_reserved!(VarMulArrayRef, buf, [T, E], [T, E]);
+
+/// Template for implementing FixedSize on views of a single T.
+macro_rules! impl_fixed_size {
+    ($target:ident, [$($gen:tt)*], [$($args:tt)*]) => {
+        impl<'a, T, $($gen)*> FixedSize for $target<'a, T, $($args)*> {
+            const SIZE: usize = core::mem::size_of::<T>();
+        }
+    };
+}
+
+impl_fixed_size!(ByteView, [], []);
+impl_fixed_size!(ByteRef, [], []);
+impl_fixed_size!(MulByteView, [E], [E]);
+impl_fixed_size!(MulByteRef, [E], [E]);
+impl_fixed_size!(ArrayView, [], []);
+impl_fixed_size!(ArrayRef, [], []);
+impl_fixed_size!(MulArrayView, [E], [E]);
+impl_fixed_size!(MulArrayRef, [E], [E]);
+
+/// Template for implementing oper assign overloading
+#[macro_export]
+macro_rules! impl_oper_assign_overload {
+    ($oper_name:ident, $bound:ident, $fname:ident, $oper:tt, $type:tt, $endian:tt) => {
+        impl<'a, $type, $endian> $oper_name<$type> for MulByteView<'a, $type, $endian>
+        where
+            MulByteView<'a, $type, $endian>: ModMulByteView<'a, $type, $endian>,
+            $type: $bound + $bound<Output = $type>,
+        {
+            fn $fname(&mut self, rhs: $type) {
+                self.set(self.val() $oper rhs)
+            }
+        }
+
+        impl<'a, $type> $oper_name<$type> for ByteView<'a, $type>
         where
             ByteView<'a, $type>: ModByteView<'a, $type>,
             $type: $bound + $bound<Output = $type>,
@@ -188,9 +1361,12 @@ impl_oper_assign_overload!(MulAssign, Mul, mul_assign, *, T, E);
 impl_oper_assign_overload!(DivAssign, Div, div_assign, /, T, E);
 
 /// A mutable array view for type [u8; L] where L is a const.
+///
+/// The view holds its slice directly. Use [`ArrayView::into_shared`] to get a
+/// [`SharedArrayView`] if the view needs to be aliased.
 #[derive(Debug, PartialEq)]
 pub struct ArrayView<'a, T> {
-    buf: Rc<RefCell<&'a mut [u8]>>,
+    buf: &'a mut [u8],
     _type: core::marker::PhantomData<T>,
 }
 
@@ -201,81 +1377,287 @@ impl<'a, T> ArrayView<'a, T> {
     ///
     /// Panics if `T.len() > arr.len()`
     pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
-        let (val, leftover) = arr.split_at_mut(core::mem::size_of::<T>());
+        let (buf, leftover) = arr.split_at_mut(core::mem::size_of::<T>());
 
         (
             Self {
-                buf: Rc::new(RefCell::new(val)),
+                buf,
                 _type: core::marker::PhantomData::<T>,
             },
             leftover,
         )
     }
+
+    /// Returns an [`ArrayView`] and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `T.len() > arr.len()`.
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let (buf, leftover) = try_split_at_mut(arr, core::mem::size_of::<T>())?;
+
+        Ok((
+            Self {
+                buf,
+                _type: core::marker::PhantomData::<T>,
+            },
+            leftover,
+        ))
+    }
+
+    /// Converts the view into a [`SharedArrayView`] which can be aliased.
+    #[cfg(feature = "alloc")]
+    pub fn into_shared(self) -> SharedArrayView<'a, T> {
+        SharedArrayView {
+            buf: Rc::new(RefCell::new(self.buf)),
+            _type: core::marker::PhantomData::<T>,
+        }
+    }
+}
+
+impl<'a, T> TryMutView<'a> for ArrayView<'a, T> {
+    fn try_mut_view_ctx(
+        arr: &'a mut [u8],
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        Self::try_mut_view(arr).map_err(|e| ctx.error(e))
+    }
 }
 
 impl<'a, const L: usize> ArrayView<'a, [u8; L]> {
     /// Returns a mutable reference to the array.
-    pub fn as_mut_ref(&self) -> RefMut<&'a mut [u8]> {
-        self.buf.borrow_mut()
+    pub fn as_mut_ref(&mut self) -> &mut [u8; L] {
+        (&mut *self.buf).try_into().unwrap()
     }
 
     /// Returns a reference to the array.
-    pub fn as_ref(&self) -> Ref<&'a mut [u8]> {
-        self.buf.borrow()
-    }
-
-    /// Retruns a clone of the reference counted pointer.
-    pub fn rc_clone(&self) -> Rc<RefCell<&'a mut [u8]>> {
-        self.buf.clone()
+    #[allow(clippy::should_implement_trait)]
+    pub fn as_ref(&self) -> &[u8; L] {
+        (&*self.buf).try_into().unwrap()
     }
 
     /// Copies bytes from a `&[u8]` source to the underlying array.
     pub fn set(&mut self, src: &[u8]) {
-        let mut dst = self.buf.borrow_mut();
+        self.buf.copy_from_slice(&src[..L])
+    }
+}
 
-        for i in 0..L {
-            dst[i] = src[i]
-        }
+impl<'a, const L: usize> EqValue for ArrayView<'a, [u8; L]> {
+    type Value = [u8; L];
+
+    fn eq_value(&self) -> [u8; L] {
+        *self.as_ref()
     }
 }
 
-/// A mutable array view for type &mut [T] (Types other than u8 should not be used for now).
+/// A mutable array view for type [u8; L] which can be aliased via reference counting.
 ///
-/// Due to the length not being known at compile time, this type *cannot* be used with the
-/// mutview derive macro.
+/// Requires the `alloc` feature. Constructed with [`ArrayView::into_shared`] or directly with
+/// `mut_view`. Each access goes through a [`RefCell`] so aliasing is checked at runtime.
+#[cfg(feature = "alloc")]
 #[derive(Debug, PartialEq)]
-pub struct VarArrayView<'a, T> {
+pub struct SharedArrayView<'a, T> {
     buf: Rc<RefCell<&'a mut [u8]>>,
     _type: core::marker::PhantomData<T>,
 }
 
-impl<'a, T> VarArrayView<'a, T> {
-    /// Returns an [`VarArrayView`] and leftover slice.
+#[cfg(feature = "alloc")]
+impl<'a, T> SharedArrayView<'a, T> {
+    /// Returns a [`SharedArrayView`] and leftover slice.
     ///
     /// # Panics
     ///
-    /// Panics if `len > arr.len()`
-    pub fn mut_view(arr: &'a mut [u8], view_len: usize) -> (Self, &'a mut [u8]) {
-        let (val, leftover) = arr.split_at_mut(view_len * core::mem::size_of::<T>());
+    /// Panics if `T.len() > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        let (view, leftover) = ArrayView::mut_view(arr);
 
-        (
-            Self {
-                buf: Rc::new(RefCell::new(val)),
-                _type: core::marker::PhantomData::<T>,
-            },
-            leftover,
-        )
+        (view.into_shared(), leftover)
+    }
+
+    /// Returns a [`SharedArrayView`] and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `T.len() > arr.len()`.
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let (view, leftover) = ArrayView::try_mut_view(arr)?;
+
+        Ok((view.into_shared(), leftover))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> TryMutView<'a> for SharedArrayView<'a, T> {
+    fn try_mut_view_ctx(
+        arr: &'a mut [u8],
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        Self::try_mut_view(arr).map_err(|e| ctx.error(e))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, const L: usize> SharedArrayView<'a, [u8; L]> {
+    /// Returns a mutable reference to the array.
+    pub fn as_mut_ref(&self) -> RefMut<'_, &'a mut [u8]> {
+        self.buf.borrow_mut()
+    }
+
+    /// Returns a reference to the array.
+    pub fn as_ref(&self) -> Ref<'_, &'a mut [u8]> {
+        self.buf.borrow()
+    }
+
+    /// Retruns a clone of the reference counted pointer.
+    pub fn rc_clone(&self) -> Rc<RefCell<&'a mut [u8]>> {
+        self.buf.clone()
+    }
+
+    /// Copies bytes from a `&[u8]` source to the underlying array.
+    pub fn set(&mut self, src: &[u8]) {
+        self.buf.borrow_mut().copy_from_slice(&src[..L])
+    }
+}
+
+/// A mutable array view for type `&mut [T]` (Types other than u8 should not be used for now).
+///
+/// Use [`VarMulArrayView`] for arrays of multi byte values. Due to the length not being known at
+/// compile time, fields of this type need a `count` or `len` attribute when used with the mutview
+/// derive macro. Use [`VarArrayView::into_shared`] to get a [`SharedVarArrayView`] if the view
+/// needs to be aliased.
+#[derive(Debug, PartialEq)]
+pub struct VarArrayView<'a, T> {
+    buf: &'a mut [u8],
+    _type: core::marker::PhantomData<T>,
+}
+
+impl<'a, T> VarArrayView<'a, T> {
+    /// Returns an [`VarArrayView`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8], view_len: usize) -> (Self, &'a mut [u8]) {
+        let (buf, leftover) = arr.split_at_mut(view_len * core::mem::size_of::<T>());
+
+        (
+            Self {
+                buf,
+                _type: core::marker::PhantomData::<T>,
+            },
+            leftover,
+        )
+    }
+
+    /// Returns an [`VarArrayView`] and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `len > arr.len()`.
+    pub fn try_mut_view(
+        arr: &'a mut [u8],
+        view_len: usize,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let byte_len = view_len.saturating_mul(core::mem::size_of::<T>());
+        let (buf, leftover) = try_split_at_mut(arr, byte_len)?;
+
+        Ok((
+            Self {
+                buf,
+                _type: core::marker::PhantomData::<T>,
+            },
+            leftover,
+        ))
+    }
+
+    /// Converts the view into a [`SharedVarArrayView`] which can be aliased.
+    #[cfg(feature = "alloc")]
+    pub fn into_shared(self) -> SharedVarArrayView<'a, T> {
+        SharedVarArrayView {
+            buf: Rc::new(RefCell::new(self.buf)),
+            _type: core::marker::PhantomData::<T>,
+        }
+    }
+}
+
+impl<'a, T> TryMutViewVar<'a> for VarArrayView<'a, T> {
+    fn try_mut_view_count_ctx(
+        arr: &'a mut [u8],
+        count: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        Self::try_mut_view(arr, count).map_err(|e| ctx.error(e))
+    }
+
+    fn try_mut_view_len_ctx(
+        arr: &'a mut [u8],
+        len: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let (buf, leftover) = try_split_at_mut(arr, len).map_err(|e| ctx.error(e))?;
+        let count = elem_count(len, core::mem::size_of::<T>()).map_err(|e| ctx.error(e))?;
+
+        Ok((Self::mut_view(buf, count).0, leftover))
     }
 }
 
 impl<'a, T> VarArrayView<'a, T> {
     /// Returns a mutable reference to the array.
-    pub fn as_mut_ref(&self) -> RefMut<&'a mut [u8]> {
+    pub fn as_mut_ref(&mut self) -> &mut [u8] {
+        self.buf
+    }
+
+    /// Returns a reference to the array.
+    #[allow(clippy::should_implement_trait)]
+    pub fn as_ref(&self) -> &[u8] {
+        self.buf
+    }
+
+    /// Copies bytes from a `&[u8]` source to the underlying array.
+    pub fn set(&mut self, src: &[u8]) {
+        let len = self.buf.len();
+
+        self.buf.copy_from_slice(&src[..len])
+    }
+}
+
+/// A mutable array view for type `&mut [T]` which can be aliased via reference counting.
+///
+/// Requires the `alloc` feature. Constructed with [`VarArrayView::into_shared`] or directly with
+/// `mut_view`. Each access goes through a [`RefCell`] so aliasing is checked at runtime.
+#[cfg(feature = "alloc")]
+#[derive(Debug, PartialEq)]
+pub struct SharedVarArrayView<'a, T> {
+    buf: Rc<RefCell<&'a mut [u8]>>,
+    _type: core::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T> SharedVarArrayView<'a, T> {
+    /// Returns a [`SharedVarArrayView`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8], view_len: usize) -> (Self, &'a mut [u8]) {
+        let (view, leftover) = VarArrayView::mut_view(arr, view_len);
+
+        (view.into_shared(), leftover)
+    }
+
+    /// Returns a [`SharedVarArrayView`] and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `len > arr.len()`.
+    pub fn try_mut_view(
+        arr: &'a mut [u8],
+        view_len: usize,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let (view, leftover) = VarArrayView::try_mut_view(arr, view_len)?;
+
+        Ok((view.into_shared(), leftover))
+    }
+
+    /// Returns a mutable reference to the array.
+    pub fn as_mut_ref(&self) -> RefMut<'_, &'a mut [u8]> {
         self.buf.borrow_mut()
     }
 
     /// Returns a reference to the array.
-    pub fn as_ref(&self) -> Ref<&'a mut [u8]> {
+    pub fn as_ref(&self) -> Ref<'_, &'a mut [u8]> {
         self.buf.borrow()
     }
 
@@ -286,11 +1668,2551 @@ impl<'a, T> VarArrayView<'a, T> {
 
     /// Copies bytes from a `&[u8]` source to the underlying array.
     pub fn set(&mut self, src: &[u8]) {
-        let len = self.buf.borrow().len();
         let mut dst = self.buf.borrow_mut();
+        let len = dst.len();
 
-        for i in 0..len {
-            dst[i] = src[i]
-        }
+        dst.copy_from_slice(&src[..len])
+    }
+}
+
+/// A read-only array view for type [u8; L] where L is a const.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ArrayRef<'a, T> {
+    buf: &'a [u8],
+    _type: core::marker::PhantomData<T>,
+}
+
+impl<'a, T> ArrayRef<'a, T> {
+    /// Returns an [`ArrayRef`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T.len() > arr.len()`
+    pub fn view(arr: &'a [u8]) -> (Self, &'a [u8]) {
+        let (buf, leftover) = arr.split_at(core::mem::size_of::<T>());
+
+        (
+            Self {
+                buf,
+                _type: core::marker::PhantomData::<T>,
+            },
+            leftover,
+        )
+    }
+
+    /// Returns an [`ArrayRef`] and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `T.len() > arr.len()`.
+    pub fn try_view(arr: &'a [u8]) -> Result<(Self, &'a [u8]), ViewError> {
+        let (buf, leftover) = try_split_at(arr, core::mem::size_of::<T>())?;
+
+        Ok((
+            Self {
+                buf,
+                _type: core::marker::PhantomData::<T>,
+            },
+            leftover,
+        ))
+    }
+}
+
+impl<'a, T> TryView<'a> for ArrayRef<'a, T> {
+    fn try_view_ctx(arr: &'a [u8], ctx: &ParseCtx) -> Result<(Self, &'a [u8]), ViewError> {
+        Self::try_view(arr).map_err(|e| ctx.error(e))
+    }
+}
+
+impl<'a, const L: usize> ArrayRef<'a, [u8; L]> {
+    /// Returns a reference to the array.
+    #[allow(clippy::should_implement_trait)]
+    pub fn as_ref(&self) -> &'a [u8; L] {
+        self.buf.try_into().unwrap()
+    }
+}
+
+impl<'a, const L: usize> EqValue for ArrayRef<'a, [u8; L]> {
+    type Value = [u8; L];
+
+    fn eq_value(&self) -> [u8; L] {
+        *self.as_ref()
+    }
+}
+
+/// A read-only array view for type `&[T]` (Types other than u8 should not be used for now).
+///
+/// Due to the length not being known at compile time, fields of this type need a `count` or `len`
+/// attribute when used with the mutview derive macro.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct VarArrayRef<'a, T> {
+    buf: &'a [u8],
+    _type: core::marker::PhantomData<T>,
+}
+
+impl<'a, T> VarArrayRef<'a, T> {
+    /// Returns a [`VarArrayRef`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len > arr.len()`
+    pub fn view(arr: &'a [u8], view_len: usize) -> (Self, &'a [u8]) {
+        let (buf, leftover) = arr.split_at(view_len * core::mem::size_of::<T>());
+
+        (
+            Self {
+                buf,
+                _type: core::marker::PhantomData::<T>,
+            },
+            leftover,
+        )
+    }
+
+    /// Returns a [`VarArrayRef`] and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `len > arr.len()`.
+    pub fn try_view(arr: &'a [u8], view_len: usize) -> Result<(Self, &'a [u8]), ViewError> {
+        let byte_len = view_len.saturating_mul(core::mem::size_of::<T>());
+        let (buf, leftover) = try_split_at(arr, byte_len)?;
+
+        Ok((
+            Self {
+                buf,
+                _type: core::marker::PhantomData::<T>,
+            },
+            leftover,
+        ))
+    }
+
+    /// Returns a reference to the array.
+    #[allow(clippy::should_implement_trait)]
+    pub fn as_ref(&self) -> &'a [u8] {
+        self.buf
+    }
+}
+
+impl<'a, T> TryViewVar<'a> for VarArrayRef<'a, T> {
+    fn try_view_count_ctx(
+        arr: &'a [u8],
+        count: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a [u8]), ViewError> {
+        Self::try_view(arr, count).map_err(|e| ctx.error(e))
+    }
+
+    fn try_view_len_ctx(
+        arr: &'a [u8],
+        len: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a [u8]), ViewError> {
+        let (buf, leftover) = try_split_at(arr, len).map_err(|e| ctx.error(e))?;
+        let count = elem_count(len, core::mem::size_of::<T>()).map_err(|e| ctx.error(e))?;
+
+        Ok((Self::view(buf, count).0, leftover))
+    }
+}
+
+/// A mutable array view for type `[T; N]` where T is a multi byte value with byte order E.
+///
+/// Elements are read and written through [`MulByteView`], so T can be any type with a
+/// [`ModMulByteView`] implementation for E whose value is T, e.g. `[u32; 16]`. E must be a
+/// [`StaticEndian`] byte order, arrays with a [`DynEnd`] byte order aren't supported.
+#[derive(Debug, PartialEq)]
+pub struct MulArrayView<'a, T, E> {
+    buf: &'a mut [u8],
+    _type: core::marker::PhantomData<(T, E)>,
+}
+
+impl<'a, T, E: StaticEndian> MulArrayView<'a, T, E> {
+    /// Returns a [`MulArrayView`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T.len() > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        let (buf, leftover) = arr.split_at_mut(core::mem::size_of::<T>());
+
+        (
+            Self {
+                buf,
+                _type: core::marker::PhantomData,
+            },
+            leftover,
+        )
+    }
+
+    /// Returns a [`MulArrayView`] and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `T.len() > arr.len()`.
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let (buf, leftover) = try_split_at_mut(arr, core::mem::size_of::<T>())?;
+
+        Ok((
+            Self {
+                buf,
+                _type: core::marker::PhantomData,
+            },
+            leftover,
+        ))
+    }
+}
+
+impl<'a, T, E: StaticEndian> TryMutView<'a> for MulArrayView<'a, T, E> {
+    fn try_mut_view_ctx(
+        arr: &'a mut [u8],
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        Self::try_mut_view(arr).map_err(|e| ctx.error(e))
+    }
+}
+
+/// A mutable array view for type `&mut [T]` where T is a multi byte value with byte order E.
+///
+/// Like [`VarArrayView`] the length is only known at runtime, so fields of this type need a `count`
+/// or `len` attribute when used with the mutview derive macro. See [`MulArrayView`] for valid T
+/// and E.
+#[derive(Debug, PartialEq)]
+pub struct VarMulArrayView<'a, T, E> {
+    buf: &'a mut [u8],
+    _type: core::marker::PhantomData<(T, E)>,
+}
+
+impl<'a, T, E: StaticEndian> VarMulArrayView<'a, T, E> {
+    /// Returns a [`VarMulArrayView`] of `view_len` elements and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `view_len * T.len() > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8], view_len: usize) -> (Self, &'a mut [u8]) {
+        let (buf, leftover) = arr.split_at_mut(view_len * core::mem::size_of::<T>());
+
+        (
+            Self {
+                buf,
+                _type: core::marker::PhantomData,
+            },
+            leftover,
+        )
+    }
+
+    /// Returns a [`VarMulArrayView`] of `view_len` elements and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `view_len * T.len() > arr.len()`.
+    pub fn try_mut_view(
+        arr: &'a mut [u8],
+        view_len: usize,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let byte_len = view_len.saturating_mul(core::mem::size_of::<T>());
+        let (buf, leftover) = try_split_at_mut(arr, byte_len)?;
+
+        Ok((
+            Self {
+                buf,
+                _type: core::marker::PhantomData,
+            },
+            leftover,
+        ))
+    }
+}
+
+/// A read-only array view for type `[T; N]` where T is a multi byte value with byte order E.
+///
+/// Elements are read through [`MulByteRef`], see [`MulArrayView`] for valid T and E.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct MulArrayRef<'a, T, E> {
+    buf: &'a [u8],
+    _type: core::marker::PhantomData<(T, E)>,
+}
+
+impl<'a, T, E: StaticEndian> MulArrayRef<'a, T, E> {
+    /// Returns a [`MulArrayRef`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T.len() > arr.len()`
+    pub fn view(arr: &'a [u8]) -> (Self, &'a [u8]) {
+        let (buf, leftover) = arr.split_at(core::mem::size_of::<T>());
+
+        (
+            Self {
+                buf,
+                _type: core::marker::PhantomData,
+            },
+            leftover,
+        )
+    }
+
+    /// Returns a [`MulArrayRef`] and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `T.len() > arr.len()`.
+    pub fn try_view(arr: &'a [u8]) -> Result<(Self, &'a [u8]), ViewError> {
+        let (buf, leftover) = try_split_at(arr, core::mem::size_of::<T>())?;
+
+        Ok((
+            Self {
+                buf,
+                _type: core::marker::PhantomData,
+            },
+            leftover,
+        ))
+    }
+}
+
+impl<'a, T, E: StaticEndian> TryView<'a> for MulArrayRef<'a, T, E> {
+    fn try_view_ctx(arr: &'a [u8], ctx: &ParseCtx) -> Result<(Self, &'a [u8]), ViewError> {
+        Self::try_view(arr).map_err(|e| ctx.error(e))
     }
 }
+
+/// A read-only array view for type `&[T]` where T is a multi byte value with byte order E.
+///
+/// The read-only counterpart of [`VarMulArrayView`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct VarMulArrayRef<'a, T, E> {
+    buf: &'a [u8],
+    _type: core::marker::PhantomData<(T, E)>,
+}
+
+impl<'a, T, E: StaticEndian> VarMulArrayRef<'a, T, E> {
+    /// Returns a [`VarMulArrayRef`] of `view_len` elements and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `view_len * T.len() > arr.len()`
+    pub fn view(arr: &'a [u8], view_len: usize) -> (Self, &'a [u8]) {
+        let (buf, leftover) = arr.split_at(view_len * core::mem::size_of::<T>());
+
+        (
+            Self {
+                buf,
+                _type: core::marker::PhantomData,
+            },
+            leftover,
+        )
+    }
+
+    /// Returns a [`VarMulArrayRef`] of `view_len` elements and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `view_len * T.len() > arr.len()`.
+    pub fn try_view(arr: &'a [u8], view_len: usize) -> Result<(Self, &'a [u8]), ViewError> {
+        let byte_len = view_len.saturating_mul(core::mem::size_of::<T>());
+        let (buf, leftover) = try_split_at(arr, byte_len)?;
+
+        Ok((
+            Self {
+                buf,
+                _type: core::marker::PhantomData,
+            },
+            leftover,
+        ))
+    }
+}
+
+impl<'a, T, E: StaticEndian> TryViewVar<'a> for VarMulArrayRef<'a, T, E> {
+    fn try_view_count_ctx(
+        arr: &'a [u8],
+        count: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a [u8]), ViewError> {
+        Self::try_view(arr, count).map_err(|e| ctx.error(e))
+    }
+
+    fn try_view_len_ctx(
+        arr: &'a [u8],
+        len: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a [u8]), ViewError> {
+        let (buf, leftover) = try_split_at(arr, len).map_err(|e| ctx.error(e))?;
+        let count = elem_count(len, core::mem::size_of::<T>()).map_err(|e| ctx.error(e))?;
+
+        Ok((Self::view(buf, count).0, leftover))
+    }
+}
+
+impl<'a, T, E: StaticEndian> TryMutViewVar<'a> for VarMulArrayView<'a, T, E> {
+    fn try_mut_view_count_ctx(
+        arr: &'a mut [u8],
+        count: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        Self::try_mut_view(arr, count).map_err(|e| ctx.error(e))
+    }
+
+    fn try_mut_view_len_ctx(
+        arr: &'a mut [u8],
+        len: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let (buf, leftover) = try_split_at_mut(arr, len).map_err(|e| ctx.error(e))?;
+        let count = elem_count(len, core::mem::size_of::<T>()).map_err(|e| ctx.error(e))?;
+
+        Ok((Self::mut_view(buf, count).0, leftover))
+    }
+}
+
+/// Template for implementing the element accessors of multi byte array views, and the setters of
+/// mutable ones.
+macro_rules! impl_mularray_elems {
+    ($target:ty, $elem:ident, [$($gen:tt)*]) => {
+        impl<$($gen)*> $target
+        where
+            E: StaticEndian,
+            for<'b> MulByteRef<'b, $elem, E>: RefMulByteView<'b, $elem, E>,
+        {
+            /// Returns the number of elements.
+            pub fn len(&self) -> usize {
+                self.buf.len() / core::mem::size_of::<$elem>()
+            }
+
+            /// Returns true if the array has no elements.
+            pub fn is_empty(&self) -> bool {
+                self.buf.is_empty()
+            }
+
+            /// Returns a copy of the element at `i`, or `None` if `i` is out of bounds.
+            pub fn get(&self, i: usize) -> Option<$elem> {
+                if i >= self.len() {
+                    return None;
+                }
+
+                let size = core::mem::size_of::<$elem>();
+                let bytes = &self.buf[i * size..(i + 1) * size];
+
+                Some(MulByteRef::<$elem, E>::view(bytes).0.val())
+            }
+
+            /// Returns an iterator over copies of the elements.
+            pub fn iter(&self) -> impl Iterator<Item = $elem> + '_ {
+                self.buf
+                    .chunks_exact(core::mem::size_of::<$elem>())
+                    .map(|c| MulByteRef::<$elem, E>::view(c).0.val())
+            }
+
+            /// Reads every element of the array into `dst`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `dst.len() != self.len()`
+            pub fn copy_to(&self, dst: &mut [$elem]) {
+                assert_eq!(dst.len(), self.len(), "destination and array lengths differ");
+
+                for (d, v) in dst.iter_mut().zip(self.iter()) {
+                    *d = v;
+                }
+            }
+        }
+    };
+    (mut $target:ty, $elem:ident, [$($gen:tt)*]) => {
+        impl_mularray_elems!($target, $elem, [$($gen)*]);
+
+        impl<$($gen)*> $target
+        where
+            E: StaticEndian,
+            for<'b> MulByteRef<'b, $elem, E>: RefMulByteView<'b, $elem, E>,
+            for<'b> MulByteView<'b, $elem, E>: ModMulByteView<'b, $elem, E>,
+        {
+            /// Sets the element at `i` to v.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `i >= self.len()`
+            pub fn set(&mut self, i: usize, v: $elem) {
+                let size = core::mem::size_of::<$elem>();
+
+                MulByteView::<$elem, E>::mut_view(&mut self.buf[i * size..(i + 1) * size])
+                    .0
+                    .set(v)
+            }
+
+            /// Returns an iterator over mutable views of the elements.
+            pub fn iter_mut(&mut self) -> impl Iterator<Item = MulByteView<'_, $elem, E>> {
+                self.buf
+                    .chunks_exact_mut(core::mem::size_of::<$elem>())
+                    .map(|c| MulByteView::mut_view(c).0)
+            }
+
+            /// Writes every element of `src` to the array.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `src.len() != self.len()`
+            pub fn copy_from(&mut self, src: &[$elem]) {
+                assert_eq!(src.len(), self.len(), "source and array lengths differ");
+
+                for (mut e, v) in self.iter_mut().zip(src) {
+                    e.set(*v);
+                }
+            }
+        }
+    };
+}
+
+impl_mularray_elems!(mut MulArrayView<'a, [T; N], E>, T, ['a, T: Copy, E, const N: usize]);
+impl_mularray_elems!(mut VarMulArrayView<'a, T, E>, T, ['a, T: Copy, E]);
+impl_mularray_elems!(MulArrayRef<'a, [T; N], E>, T, ['a, T: Copy, E, const N: usize]);
+impl_mularray_elems!(VarMulArrayRef<'a, T, E>, T, ['a, T: Copy, E]);
+
+/// A fixed length array of N views of type T, parsed one after another.
+///
+/// T can be any fixed length view, including structs using the [`MutView`](crate::MutView) derive,
+/// e.g. `ViewArray<SectionHeader<'a>, 16>`. Derefs to `[T]`. A [`ViewError`] from element `i`
+/// has `[i]` in its path.
+#[derive(Debug, PartialEq)]
+pub struct ViewArray<T, const N: usize> {
+    items: [T; N],
+}
+
+impl<T, const N: usize> ViewArray<T, N> {
+    /// Returns a [`ViewArray`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the N views don't fit in `arr`
+    pub fn mut_view<'a>(arr: &'a mut [u8]) -> (Self, &'a mut [u8])
+    where
+        T: TryMutView<'a>,
+    {
+        match Self::try_mut_view(arr) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a [`ViewArray`] and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if the N views don't fit in `arr`.
+    pub fn try_mut_view<'a>(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8]), ViewError>
+    where
+        T: TryMutView<'a>,
+    {
+        <Self as TryMutView<'a>>::try_mut_view_ctx(arr, &ParseCtx::default())
+    }
+
+    /// Returns a read-only [`ViewArray`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the N views don't fit in `arr`
+    pub fn view<'a>(arr: &'a [u8]) -> (Self, &'a [u8])
+    where
+        T: TryView<'a>,
+    {
+        match Self::try_view(arr) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a read-only [`ViewArray`] and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if the N views don't fit in `arr`.
+    pub fn try_view<'a>(arr: &'a [u8]) -> Result<(Self, &'a [u8]), ViewError>
+    where
+        T: TryView<'a>,
+    {
+        <Self as TryView<'a>>::try_view_ctx(arr, &ParseCtx::default())
+    }
+
+    /// Returns the views as an array.
+    pub fn into_inner(self) -> [T; N] {
+        self.items
+    }
+}
+
+impl<'a, T: TryMutView<'a>, const N: usize> TryMutView<'a> for ViewArray<T, N> {
+    fn try_mut_view_ctx(
+        arr: &'a mut [u8],
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let len = arr.len();
+        let mut rest = Some(arr);
+        let mut err = None;
+
+        // Stops parsing at the first error, leaving the remaining elements as None.
+        let items = core::array::from_fn(|i| {
+            let buf = rest.take()?;
+            let offset = len - buf.len();
+
+            match T::try_mut_view_ctx(buf, &ctx.index(i, offset)) {
+                Ok((v, leftover)) => {
+                    rest = Some(leftover);
+                    Some(v)
+                }
+                Err(e) => {
+                    err = Some(e);
+                    None
+                }
+            }
+        });
+
+        if let Some(e) = err {
+            return Err(e);
+        }
+
+        let items = items.map(Option::unwrap);
+        Ok((Self { items }, rest.unwrap()))
+    }
+}
+
+impl<'a, T: TryView<'a>, const N: usize> TryView<'a> for ViewArray<T, N> {
+    fn try_view_ctx(arr: &'a [u8], ctx: &ParseCtx) -> Result<(Self, &'a [u8]), ViewError> {
+        let len = arr.len();
+        let mut rest = arr;
+        let mut err = None;
+
+        // Stops parsing at the first error, leaving the remaining elements as None.
+        let items = core::array::from_fn(|i| {
+            if err.is_some() {
+                return None;
+            }
+
+            match T::try_view_ctx(rest, &ctx.index(i, len - rest.len())) {
+                Ok((v, leftover)) => {
+                    rest = leftover;
+                    Some(v)
+                }
+                Err(e) => {
+                    err = Some(e);
+                    None
+                }
+            }
+        });
+
+        if let Some(e) = err {
+            return Err(e);
+        }
+
+        let items = items.map(Option::unwrap);
+        Ok((Self { items }, rest))
+    }
+}
+
+impl<T: FixedSize, const N: usize> FixedSize for ViewArray<T, N> {
+    const SIZE: usize = T::SIZE * N;
+}
+
+impl<T, const N: usize> core::ops::Deref for ViewArray<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.items
+    }
+}
+
+impl<T, const N: usize> core::ops::DerefMut for ViewArray<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.items
+    }
+}
+
+impl<T, const N: usize> IntoIterator for ViewArray<T, N> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIterator::into_iter(self.items)
+    }
+}
+
+/// An array of views of type T whose count is only known at runtime, parsed one after another.
+///
+/// Like [`ViewArray`] but the views are held in a `Vec`, so the `alloc` feature is needed. Due to
+/// the count not being known at compile time, fields of this type need a `count` or `len`
+/// attribute when used with the mutview derive macro.
+#[cfg(feature = "alloc")]
+#[derive(Debug, PartialEq)]
+pub struct VarViewArray<T> {
+    items: Vec<T>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> VarViewArray<T> {
+    /// Returns a [`VarViewArray`] of `count` views and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the views don't fit in `arr`
+    pub fn mut_view<'a>(arr: &'a mut [u8], count: usize) -> (Self, &'a mut [u8])
+    where
+        T: TryMutView<'a>,
+    {
+        match Self::try_mut_view(arr, count) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a [`VarViewArray`] of `count` views and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if the views don't fit in `arr`.
+    pub fn try_mut_view<'a>(
+        arr: &'a mut [u8],
+        count: usize,
+    ) -> Result<(Self, &'a mut [u8]), ViewError>
+    where
+        T: TryMutView<'a>,
+    {
+        Self::try_mut_view_ctx(arr, count, &ParseCtx::default())
+    }
+
+    /// Returns a [`VarViewArray`] of `count` views and leftover slice, attaching the field path
+    /// and absolute offset held by `ctx` to any [`ViewError`].
+    pub fn try_mut_view_ctx<'a>(
+        arr: &'a mut [u8],
+        count: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError>
+    where
+        T: TryMutView<'a>,
+    {
+        let len = arr.len();
+        let mut rest = arr;
+        // Grown as elements are parsed, so an untrusted count can't cause a huge allocation.
+        let mut items = Vec::new();
+
+        for i in 0..count {
+            let offset = len - rest.len();
+            let (v, leftover) = T::try_mut_view_ctx(rest, &ctx.index(i, offset))?;
+
+            items.push(v);
+            rest = leftover;
+        }
+
+        Ok((Self { items }, rest))
+    }
+
+    /// Returns a read-only [`VarViewArray`] of `count` views and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the views don't fit in `arr`
+    pub fn view<'a>(arr: &'a [u8], count: usize) -> (Self, &'a [u8])
+    where
+        T: TryView<'a>,
+    {
+        match Self::try_view(arr, count) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a read-only [`VarViewArray`] of `count` views and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if the views don't fit in `arr`.
+    pub fn try_view<'a>(arr: &'a [u8], count: usize) -> Result<(Self, &'a [u8]), ViewError>
+    where
+        T: TryView<'a>,
+    {
+        Self::try_view_ctx(arr, count, &ParseCtx::default())
+    }
+
+    /// Returns a read-only [`VarViewArray`] of `count` views and leftover slice, attaching the
+    /// field path and absolute offset held by `ctx` to any [`ViewError`].
+    pub fn try_view_ctx<'a>(
+        arr: &'a [u8],
+        count: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a [u8]), ViewError>
+    where
+        T: TryView<'a>,
+    {
+        let len = arr.len();
+        let mut rest = arr;
+        // Grown as elements are parsed, so an untrusted count can't cause a huge allocation.
+        let mut items = Vec::new();
+
+        for i in 0..count {
+            let (v, leftover) = T::try_view_ctx(rest, &ctx.index(i, len - rest.len()))?;
+
+            items.push(v);
+            rest = leftover;
+        }
+
+        Ok((Self { items }, rest))
+    }
+
+    /// Returns the views as a `Vec`.
+    pub fn into_inner(self) -> Vec<T> {
+        self.items
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: TryMutView<'a>> TryMutViewVar<'a> for VarViewArray<T> {
+    fn try_mut_view_count_ctx(
+        arr: &'a mut [u8],
+        count: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        Self::try_mut_view_ctx(arr, count, ctx)
+    }
+
+    fn try_mut_view_len_ctx(
+        arr: &'a mut [u8],
+        len: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let (mut rest, leftover) = try_split_at_mut(arr, len).map_err(|e| ctx.error(e))?;
+        let mut items = Vec::new();
+
+        while !rest.is_empty() {
+            let i = items.len();
+            let offset = len - rest.len();
+            let (v, r) = T::try_mut_view_ctx(rest, &ctx.index(i, offset))?;
+
+            // A view which consumes no bytes would never fill the length.
+            if len - r.len() == offset {
+                return Err(ctx.index(i, offset).error(ViewError::invalid_length()));
+            }
+
+            items.push(v);
+            rest = r;
+        }
+
+        Ok((Self { items }, leftover))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: TryView<'a>> TryViewVar<'a> for VarViewArray<T> {
+    fn try_view_count_ctx(
+        arr: &'a [u8],
+        count: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a [u8]), ViewError> {
+        Self::try_view_ctx(arr, count, ctx)
+    }
+
+    fn try_view_len_ctx(
+        arr: &'a [u8],
+        len: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a [u8]), ViewError> {
+        let (mut rest, leftover) = try_split_at(arr, len).map_err(|e| ctx.error(e))?;
+        let mut items = Vec::new();
+
+        while !rest.is_empty() {
+            let i = items.len();
+            let offset = len - rest.len();
+            let (v, r) = T::try_view_ctx(rest, &ctx.index(i, offset))?;
+
+            // A view which consumes no bytes would never fill the length.
+            if len - r.len() == offset {
+                return Err(ctx.index(i, offset).error(ViewError::invalid_length()));
+            }
+
+            items.push(v);
+            rest = r;
+        }
+
+        Ok((Self { items }, leftover))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> core::ops::Deref for VarViewArray<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.items
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> core::ops::DerefMut for VarViewArray<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.items
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> IntoIterator for VarViewArray<T> {
+    type Item = T;
+    type IntoIter = alloc::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+/// Views the element of a [`LazyViewArray`] at the start of `arr` for the borrow `'b`.
+fn try_reborrow_view<'b, T: Reborrow<'b>>(
+    arr: &'b mut [u8],
+    ctx: &ParseCtx,
+) -> Result<(T::Target, &'b mut [u8]), ViewError>
+where
+    T::Target: TryMutView<'b>,
+{
+    T::Target::try_mut_view_ctx(arr, ctx)
+}
+
+/// An array of views of type T whose count is only known at runtime, parsed lazily.
+///
+/// Unlike [`VarViewArray`] it doesn't need the `alloc` feature, as it only holds the bytes of the
+/// array and views each element again as it is iterated with [`iter_mut`](Self::iter_mut). The
+/// views borrow the array, so T must implement [`Reborrow`]. Every element is checked when the
+/// array is viewed, but an element written through an earlier view may no longer parse, so
+/// errors are still returned when iterating and name the element relative to the array, e.g.
+/// `[1].value`. Elements which implement [`FixedSize`] can also be viewed directly with
+/// [`get_mut`](Self::get_mut).
+/// Fields of this type need a `count` or `len` attribute when used with the mutview derive macro.
+#[derive(Debug)]
+pub struct LazyViewArray<'a, T> {
+    arr: &'a mut [u8],
+    count: usize,
+    ctx: ParseCtx<'static>,
+    _type: core::marker::PhantomData<T>,
+}
+
+impl<'a, T> LazyViewArray<'a, T>
+where
+    T: for<'b> Reborrow<'b>,
+    for<'b> <T as Reborrow<'b>>::Target: TryMutView<'b>,
+{
+    /// Returns a [`LazyViewArray`] of `count` views and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the views don't fit in `arr`
+    pub fn mut_view(arr: &'a mut [u8], count: usize) -> (Self, &'a mut [u8]) {
+        match Self::try_mut_view(arr, count) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a [`LazyViewArray`] of `count` views and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if the views don't fit in `arr`.
+    pub fn try_mut_view(
+        arr: &'a mut [u8],
+        count: usize,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        Self::try_mut_view_ctx(arr, count, &ParseCtx::default())
+    }
+
+    /// Returns a [`LazyViewArray`] of `count` views and leftover slice, attaching the field path
+    /// and absolute offset held by `ctx` to any [`ViewError`].
+    pub fn try_mut_view_ctx(
+        arr: &'a mut [u8],
+        count: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let len = arr.len();
+        let mut rest = &mut *arr;
+
+        for i in 0..count {
+            let offset = len - rest.len();
+            let (_, leftover) = try_reborrow_view::<T>(rest, &ctx.index(i, offset))?;
+
+            rest = leftover;
+        }
+
+        let used = len - rest.len();
+        let (arr, leftover) = arr.split_at_mut(used);
+
+        Ok((
+            Self {
+                arr,
+                count,
+                ctx: ctx.detach(),
+                _type: core::marker::PhantomData::<T>,
+            },
+            leftover,
+        ))
+    }
+
+    /// Returns the number of views in the array.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns true if the array holds no views.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns an iterator viewing each element in turn, borrowing the array.
+    ///
+    /// Stops after the first [`ViewError`].
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl Iterator<Item = Result<<T as Reborrow<'_>>::Target, ViewError>> + '_ {
+        let ctx = self.ctx;
+        let count = self.count;
+        let len = self.arr.len();
+        let mut rest = &mut *self.arr;
+        let mut i = 0;
+
+        core::iter::from_fn(move || {
+            if i >= count {
+                return None;
+            }
+
+            let offset = len - rest.len();
+            let res = try_reborrow_view::<T>(core::mem::take(&mut rest), &ctx.index(i, offset));
+
+            i += 1;
+            match res {
+                Ok((v, leftover)) => {
+                    rest = leftover;
+                    Some(Ok(v))
+                }
+                Err(e) => {
+                    i = count;
+                    Some(Err(e))
+                }
+            }
+        })
+    }
+}
+
+impl<'a, T> LazyViewArray<'a, T>
+where
+    T: FixedSize + for<'b> Reborrow<'b>,
+    for<'b> <T as Reborrow<'b>>::Target: TryMutView<'b>,
+{
+    /// Returns a view of the element at `index`, borrowing the array, or `None` if `index` is out
+    /// of bounds.
+    ///
+    /// Elements of a fixed size are viewed directly, without viewing the ones before them.
+    pub fn get_mut(
+        &mut self,
+        index: usize,
+    ) -> Option<Result<<T as Reborrow<'_>>::Target, ViewError>> {
+        if index >= self.count {
+            return None;
+        }
+
+        let offset = index * T::SIZE;
+        let res = try_reborrow_view::<T>(&mut self.arr[offset..], &self.ctx.index(index, offset));
+
+        Some(res.map(|(v, _)| v))
+    }
+}
+
+impl<'a, T> TryMutViewVar<'a> for LazyViewArray<'a, T>
+where
+    T: for<'b> Reborrow<'b>,
+    for<'b> <T as Reborrow<'b>>::Target: TryMutView<'b>,
+{
+    fn try_mut_view_count_ctx(
+        arr: &'a mut [u8],
+        count: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        Self::try_mut_view_ctx(arr, count, ctx)
+    }
+
+    fn try_mut_view_len_ctx(
+        arr: &'a mut [u8],
+        len: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let (arr, leftover) = try_split_at_mut(arr, len).map_err(|e| ctx.error(e))?;
+        let mut rest = &mut *arr;
+        let mut count = 0;
+
+        while !rest.is_empty() {
+            let offset = len - rest.len();
+            let (_, r) = try_reborrow_view::<T>(rest, &ctx.index(count, offset))?;
+
+            // A view which consumes no bytes would never fill the length.
+            if len - r.len() == offset {
+                return Err(ctx.index(count, offset).error(ViewError::invalid_length()));
+            }
+
+            count += 1;
+            rest = r;
+        }
+
+        Ok((
+            Self {
+                arr,
+                count,
+                ctx: ctx.detach(),
+                _type: core::marker::PhantomData::<T>,
+            },
+            leftover,
+        ))
+    }
+}
+
+/// A read-only array of views of type T whose count is only known at runtime, parsed lazily.
+///
+/// The read-only counterpart of [`LazyViewArray`]. Every element is checked when the array is
+/// viewed, so [`iter`](Self::iter) views them again without failing, and elements which implement
+/// [`FixedSize`] can be viewed directly with [`get`](Self::get).
+/// Fields of this type need a `count` or `len` attribute when used with the mutview derive macro.
+#[derive(Debug, Clone, Copy)]
+pub struct LazyViewArrayRef<'a, T> {
+    arr: &'a [u8],
+    count: usize,
+    ctx: ParseCtx<'static>,
+    _type: core::marker::PhantomData<T>,
+}
+
+impl<'a, T: TryView<'a>> LazyViewArrayRef<'a, T> {
+    /// Returns a [`LazyViewArrayRef`] of `count` views and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the views don't fit in `arr`
+    pub fn view(arr: &'a [u8], count: usize) -> (Self, &'a [u8]) {
+        match Self::try_view(arr, count) {
+            Ok(v) => v,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Returns a [`LazyViewArrayRef`] of `count` views and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if the views don't fit in `arr`.
+    pub fn try_view(arr: &'a [u8], count: usize) -> Result<(Self, &'a [u8]), ViewError> {
+        Self::try_view_ctx(arr, count, &ParseCtx::default())
+    }
+
+    /// Returns a [`LazyViewArrayRef`] of `count` views and leftover slice, attaching the field
+    /// path and absolute offset held by `ctx` to any [`ViewError`].
+    pub fn try_view_ctx(
+        arr: &'a [u8],
+        count: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a [u8]), ViewError> {
+        let mut rest = arr;
+
+        for i in 0..count {
+            let (_, leftover) = T::try_view_ctx(rest, &ctx.index(i, arr.len() - rest.len()))?;
+
+            rest = leftover;
+        }
+
+        let (arr, leftover) = arr.split_at(arr.len() - rest.len());
+
+        Ok((
+            Self {
+                arr,
+                count,
+                ctx: ctx.detach(),
+                _type: core::marker::PhantomData::<T>,
+            },
+            leftover,
+        ))
+    }
+
+    /// Returns the number of views in the array.
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    /// Returns true if the array holds no views.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Returns an iterator viewing each element in turn.
+    pub fn iter(&self) -> impl Iterator<Item = T> + 'a {
+        let ctx = self.ctx;
+        let len = self.arr.len();
+        let mut rest = self.arr;
+
+        // Every element was viewed successfully before, so viewing it again can't fail.
+        (0..self.count).map_while(move |i| {
+            let (v, leftover) = T::try_view_ctx(rest, &ctx.index(i, len - rest.len())).ok()?;
+
+            rest = leftover;
+            Some(v)
+        })
+    }
+}
+
+impl<'a, T: TryView<'a> + FixedSize> LazyViewArrayRef<'a, T> {
+    /// Returns a view of the element at `index`, or `None` if `index` is out of bounds.
+    ///
+    /// Elements of a fixed size are viewed directly, without viewing the ones before them.
+    pub fn get(&self, index: usize) -> Option<T> {
+        if index >= self.count {
+            return None;
+        }
+
+        let offset = index * T::SIZE;
+        let (v, _) = T::try_view_ctx(&self.arr[offset..], &self.ctx.index(index, offset)).ok()?;
+
+        Some(v)
+    }
+}
+
+impl<'a, T: TryView<'a>> TryViewVar<'a> for LazyViewArrayRef<'a, T> {
+    fn try_view_count_ctx(
+        arr: &'a [u8],
+        count: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a [u8]), ViewError> {
+        Self::try_view_ctx(arr, count, ctx)
+    }
+
+    fn try_view_len_ctx(
+        arr: &'a [u8],
+        len: usize,
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a [u8]), ViewError> {
+        let (arr, leftover) = try_split_at(arr, len).map_err(|e| ctx.error(e))?;
+        let mut rest = arr;
+        let mut count = 0;
+
+        while !rest.is_empty() {
+            let offset = len - rest.len();
+            let (_, r) = T::try_view_ctx(rest, &ctx.index(count, offset))?;
+
+            // A view which consumes no bytes would never fill the length.
+            if len - r.len() == offset {
+                return Err(ctx.index(count, offset).error(ViewError::invalid_length()));
+            }
+
+            count += 1;
+            rest = r;
+        }
+
+        Ok((
+            Self {
+                arr,
+                count,
+                ctx: ctx.detach(),
+                _type: core::marker::PhantomData::<T>,
+            },
+            leftover,
+        ))
+    }
+}
+
+impl<'a, 'b, T> Reborrow<'b> for ByteView<'a, T> {
+    type Target = ByteView<'b, T>;
+}
+
+impl<'a, 'b, T, E> Reborrow<'b> for MulByteView<'a, T, E> {
+    type Target = MulByteView<'b, T, E>;
+}
+
+impl<'a, 'b, T> Reborrow<'b> for ArrayView<'a, T> {
+    type Target = ArrayView<'b, T>;
+}
+
+impl<'a, 'b, T, E> Reborrow<'b> for MulArrayView<'a, T, E> {
+    type Target = MulArrayView<'b, T, E>;
+}
+
+impl<'a, 'b, F: Flags, E> Reborrow<'b> for FlagsView<'a, F, E> {
+    type Target = FlagsView<'b, F, E>;
+}
+
+impl<'a, 'b, En, Repr, E> Reborrow<'b> for EnumView<'a, En, Repr, E> {
+    type Target = EnumView<'b, En, Repr, E>;
+}
+
+impl<'b, T: Reborrow<'b>, const N: usize> Reborrow<'b> for ViewArray<T, N> {
+    type Target = ViewArray<T::Target, N>;
+}
+
+/// Marker type used with [`BitView`] to number bits from the least significant bit of the word.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Lsb0;
+/// Marker type used with [`BitView`] to number bits from the most significant bit of the word,
+/// as in protocol diagrams such as RFC 791.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Msb0;
+
+/// Bit numbering used by a [`BitView`], see [`Lsb0`] and [`Msb0`].
+pub trait BitOrder {
+    /// Returns how far the `len` bit field numbered from `start` is shifted up in a `bits` wide
+    /// word.
+    fn shift(bits: u32, start: u32, len: u32) -> u32;
+}
+
+impl BitOrder for Lsb0 {
+    fn shift(_bits: u32, start: u32, _len: u32) -> u32 {
+        start
+    }
+}
+
+impl BitOrder for Msb0 {
+    fn shift(bits: u32, start: u32, len: u32) -> u32 {
+        bits - start - len
+    }
+}
+
+/// Unsigned integers which can hold the word of a [`BitView`].
+pub trait BitWord: Copy {
+    /// Width of the word in bits.
+    const BITS: u32;
+    /// Widens the word to a [`u128`].
+    fn to_u128(self) -> u128;
+    /// Narrows `v` to the word, keeping its low bits.
+    fn from_u128(v: u128) -> Self;
+}
+
+/// Template for implementing BitWord.
+macro_rules! impl_bitword {
+    ($type:tt) => {
+        impl BitWord for $type {
+            const BITS: u32 = $type::BITS;
+
+            fn to_u128(self) -> u128 {
+                self as u128
+            }
+
+            fn from_u128(v: u128) -> Self {
+                v as $type
+            }
+        }
+    };
+}
+
+impl_bitword!(u8);
+impl_bitword!(u16);
+impl_bitword!(u32);
+impl_bitword!(u64);
+impl_bitword!(u128);
+
+/// A mutable view of `LEN` bits starting at bit `START` of a word of type T with byte order E.
+///
+/// Bits are numbered by O, which defaults to [`Lsb0`]. Several views can share the same word via
+/// [`BitView::share`], which is how the [`MutView`](crate::MutView) derive implements fields
+/// marked `#[zordon(shared)]`.
+#[derive(Debug, PartialEq)]
+pub struct BitView<'a, T, E, const START: u32, const LEN: u32, O = Lsb0> {
+    word: &'a [Cell<u8>],
+    _type: core::marker::PhantomData<(T, E, O)>,
+}
+
+impl<'a, T: BitWord, E, const START: u32, const LEN: u32, O: BitOrder>
+    BitView<'a, T, E, START, LEN, O>
+{
+    /// Fails to compile if the bit range doesn't fit in T.
+    const VALID: () = assert!(
+        LEN > 0 && LEN <= T::BITS && START <= T::BITS - LEN,
+        "bit range does not fit in the word"
+    );
+
+    fn from_cells(word: &'a [Cell<u8>]) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID;
+
+        Self {
+            word,
+            _type: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns a [`BitView`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T.len() > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        let (word, leftover) = arr.split_at_mut(core::mem::size_of::<T>());
+
+        (
+            Self::from_cells(Cell::from_mut(word).as_slice_of_cells()),
+            leftover,
+        )
+    }
+
+    /// Returns a [`BitView`] and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `T.len() > arr.len()`.
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let (word, leftover) = try_split_at_mut(arr, core::mem::size_of::<T>())?;
+
+        Ok((
+            Self::from_cells(Cell::from_mut(word).as_slice_of_cells()),
+            leftover,
+        ))
+    }
+
+    /// Returns another view of a bit range within the same word.
+    pub fn share<const S: u32, const L: u32, P: BitOrder>(&self) -> BitView<'a, T, E, S, L, P> {
+        BitView::from_cells(self.word)
+    }
+
+    /// Largest value the view can hold.
+    pub fn max() -> T {
+        T::from_u128(Self::mask())
+    }
+
+    fn mask() -> u128 {
+        u128::MAX >> (128 - LEN)
+    }
+
+    fn shift() -> u32 {
+        O::shift(T::BITS, START, LEN)
+    }
+}
+
+impl<'a, T, E, const START: u32, const LEN: u32, O> BitView<'a, T, E, START, LEN, O>
+where
+    T: BitWord,
+    E: StaticEndian,
+    O: BitOrder,
+    for<'b> MulByteRef<'b, T, E>: RefMulByteView<'b, T, E>,
+    for<'b> MulByteView<'b, T, E>: ModMulByteView<'b, T, E>,
+{
+    fn load(&self) -> u128 {
+        let mut buf = [0u8; 16];
+        let buf = &mut buf[..self.word.len()];
+        for (b, c) in buf.iter_mut().zip(self.word) {
+            *b = c.get();
+        }
+
+        MulByteRef::<T, E>::view(buf).0.val().to_u128()
+    }
+
+    fn store(&self, word: u128) {
+        let mut buf = [0u8; 16];
+        let buf = &mut buf[..self.word.len()];
+        MulByteView::<T, E>::mut_view(buf).0.set(T::from_u128(word));
+
+        for (c, b) in self.word.iter().zip(buf.iter()) {
+            c.set(*b);
+        }
+    }
+
+    /// Return a copy of the bit field.
+    pub fn val(&self) -> T {
+        T::from_u128((self.load() >> Self::shift()) & Self::mask())
+    }
+
+    /// Set the bit field to v, keeping the low `LEN` bits of values which don't fit.
+    ///
+    /// Every other bit of the word is left untouched.
+    pub fn set(&mut self, v: T) {
+        let mask = Self::mask() << Self::shift();
+        let bits = (v.to_u128() << Self::shift()) & mask;
+
+        self.store((self.load() & !mask) | bits)
+    }
+
+    /// Sets the bit field, returning an [`OutOfRange`] error if v does not fit in `LEN` bits.
+    pub fn try_set(&mut self, v: T) -> Result<(), OutOfRange> {
+        if v.to_u128() > Self::mask() {
+            return Err(OutOfRange {
+                value: v.to_u128().try_into().unwrap_or(i128::MAX),
+                min: 0,
+                max: Self::mask().try_into().unwrap_or(i128::MAX),
+            });
+        }
+
+        self.set(v);
+        Ok(())
+    }
+}
+
+impl<'a, T: BitWord, E, const START: u32, const LEN: u32, O: BitOrder> TryMutView<'a>
+    for BitView<'a, T, E, START, LEN, O>
+{
+    fn try_mut_view_ctx(
+        arr: &'a mut [u8],
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        Self::try_mut_view(arr).map_err(|e| ctx.error(e))
+    }
+}
+
+/// A read-only view of `LEN` bits starting at bit `START` of a word of type T with byte order E.
+///
+/// Bits are numbered by O, which defaults to [`Lsb0`]. Several views can share the same word via
+/// [`BitRef::share`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct BitRef<'a, T, E, const START: u32, const LEN: u32, O = Lsb0> {
+    word: &'a [u8],
+    _type: core::marker::PhantomData<(T, E, O)>,
+}
+
+impl<'a, T: BitWord, E, const START: u32, const LEN: u32, O: BitOrder>
+    BitRef<'a, T, E, START, LEN, O>
+{
+    fn from_slice(word: &'a [u8]) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = BitView::<'a, T, E, START, LEN, O>::VALID;
+
+        Self {
+            word,
+            _type: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns a [`BitRef`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T.len() > arr.len()`
+    pub fn view(arr: &'a [u8]) -> (Self, &'a [u8]) {
+        let (word, leftover) = arr.split_at(core::mem::size_of::<T>());
+
+        (Self::from_slice(word), leftover)
+    }
+
+    /// Returns a [`BitRef`] and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `T.len() > arr.len()`.
+    pub fn try_view(arr: &'a [u8]) -> Result<(Self, &'a [u8]), ViewError> {
+        let (word, leftover) = try_split_at(arr, core::mem::size_of::<T>())?;
+
+        Ok((Self::from_slice(word), leftover))
+    }
+
+    /// Returns another view of a bit range within the same word.
+    pub fn share<const S: u32, const L: u32, P: BitOrder>(&self) -> BitRef<'a, T, E, S, L, P> {
+        BitRef::from_slice(self.word)
+    }
+
+    /// Largest value the view can hold.
+    pub fn max() -> T {
+        BitView::<'a, T, E, START, LEN, O>::max()
+    }
+}
+
+impl<'a, T, E, const START: u32, const LEN: u32, O> BitRef<'a, T, E, START, LEN, O>
+where
+    T: BitWord,
+    E: StaticEndian,
+    O: BitOrder,
+    MulByteRef<'a, T, E>: RefMulByteView<'a, T, E>,
+{
+    /// Return a copy of the bit field.
+    pub fn val(&self) -> T {
+        let word = MulByteRef::<T, E>::view(self.word).0.val().to_u128();
+        let shift = BitView::<'a, T, E, START, LEN, O>::shift();
+
+        T::from_u128((word >> shift) & BitView::<'a, T, E, START, LEN, O>::mask())
+    }
+}
+
+impl<'a, T: BitWord, E, const START: u32, const LEN: u32, O: BitOrder> TryView<'a>
+    for BitRef<'a, T, E, START, LEN, O>
+{
+    fn try_view_ctx(arr: &'a [u8], ctx: &ParseCtx) -> Result<(Self, &'a [u8]), ViewError> {
+        Self::try_view(arr).map_err(|e| ctx.error(e))
+    }
+}
+
+impl<'a, T, E, const START: u32, const LEN: u32, O> LenValue for BitView<'a, T, E, START, LEN, O>
+where
+    T: BitWord + TryInto<usize>,
+    E: StaticEndian,
+    O: BitOrder,
+    for<'b> MulByteRef<'b, T, E>: RefMulByteView<'b, T, E>,
+    for<'b> MulByteView<'b, T, E>: ModMulByteView<'b, T, E>,
+{
+    fn len_value(&self) -> Option<usize> {
+        self.val().try_into().ok()
+    }
+}
+
+impl<'a, T, E, const START: u32, const LEN: u32, O> LenValue for BitRef<'a, T, E, START, LEN, O>
+where
+    T: BitWord + TryInto<usize>,
+    E: StaticEndian,
+    O: BitOrder,
+    MulByteRef<'a, T, E>: RefMulByteView<'a, T, E>,
+{
+    fn len_value(&self) -> Option<usize> {
+        self.val().try_into().ok()
+    }
+}
+
+impl<'a, T, E, const START: u32, const LEN: u32, O> EqValue for BitRef<'a, T, E, START, LEN, O>
+where
+    T: BitWord + PartialEq + Into<FieldValue>,
+    E: StaticEndian,
+    O: BitOrder,
+    MulByteRef<'a, T, E>: RefMulByteView<'a, T, E>,
+{
+    type Value = T;
+
+    fn eq_value(&self) -> T {
+        self.val()
+    }
+}
+
+impl<'a, T, E, const START: u32, const LEN: u32, O> IsZero for BitRef<'a, T, E, START, LEN, O>
+where
+    T: BitWord,
+    E: StaticEndian,
+    O: BitOrder,
+    MulByteRef<'a, T, E>: RefMulByteView<'a, T, E>,
+{
+    fn is_zero(&self) -> bool {
+        self.val().to_u128() == 0
+    }
+}
+
+impl<'a, T, E, const START: u32, const LEN: u32, O> FixedSize for BitView<'a, T, E, START, LEN, O> {
+    const SIZE: usize = core::mem::size_of::<T>();
+}
+
+impl<'a, T, E, const START: u32, const LEN: u32, O> FixedSize for BitRef<'a, T, E, START, LEN, O> {
+    const SIZE: usize = core::mem::size_of::<T>();
+}
+
+impl<'a, T, E, const START: u32, const LEN: u32, O> EqValue for BitView<'a, T, E, START, LEN, O>
+where
+    T: BitWord + PartialEq + Into<FieldValue>,
+    E: StaticEndian,
+    O: BitOrder,
+    for<'b> MulByteRef<'b, T, E>: RefMulByteView<'b, T, E>,
+    for<'b> MulByteView<'b, T, E>: ModMulByteView<'b, T, E>,
+{
+    type Value = T;
+
+    fn eq_value(&self) -> T {
+        self.val()
+    }
+}
+
+impl<'a, T, E, const START: u32, const LEN: u32, O> IsZero for BitView<'a, T, E, START, LEN, O>
+where
+    T: BitWord,
+    E: StaticEndian,
+    O: BitOrder,
+    for<'b> MulByteRef<'b, T, E>: RefMulByteView<'b, T, E>,
+    for<'b> MulByteView<'b, T, E>: ModMulByteView<'b, T, E>,
+{
+    fn is_zero(&self) -> bool {
+        self.val().to_u128() == 0
+    }
+}
+
+impl<'a, T, E, const START: u32, const LEN: u32, O> Sanitize for BitView<'a, T, E, START, LEN, O>
+where
+    T: BitWord,
+    E: StaticEndian,
+    O: BitOrder,
+    for<'b> MulByteRef<'b, T, E>: RefMulByteView<'b, T, E>,
+    for<'b> MulByteView<'b, T, E>: ModMulByteView<'b, T, E>,
+{
+    fn sanitize(&mut self) {
+        self.set(T::from_u128(0))
+    }
+}
+
+/// Implemented by flags types used as the F in [`FlagsView<'a, F, E>`], usually via [`flags!`](crate::flags!).
+pub trait Flags: Copy + 'static {
+    /// Integer the flags are stored as.
+    type Bits: BitWord;
+    /// Every named flag.
+    const FLAGS: &'static [(&'static str, Self)];
+
+    /// Returns the raw bits, including any unknown bits.
+    fn bits(&self) -> Self::Bits;
+    /// Constructs the flags from raw bits, keeping any unknown bits.
+    fn from_bits_retain(bits: Self::Bits) -> Self;
+}
+
+/// Defines a flags type implementing [`Flags`] for use with [`FlagsView`].
+///
+/// ```
+/// zordon::flags! {
+///     /// TCP header flags.
+///     pub struct TcpFlags: u8 {
+///         const FIN = 0x01;
+///         const SYN = 0x02;
+///     }
+/// }
+///
+/// assert_eq!((TcpFlags::FIN | TcpFlags::SYN).bits(), 0x03);
+/// ```
+#[macro_export]
+macro_rules! flags {
+    (
+        $(#[$outer:meta])*
+        $vis:vis struct $name:ident: $bits:ty {
+            $(
+                $(#[$inner:meta])*
+                const $flag:ident = $value:expr;
+            )*
+        }
+    ) => {
+        $(#[$outer])*
+        #[repr(transparent)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+        $vis struct $name($bits);
+
+        impl $name {
+            $(
+                $(#[$inner])*
+                pub const $flag: Self = Self($value);
+            )*
+
+            /// Returns flags with no bits set.
+            pub const fn empty() -> Self {
+                Self(0)
+            }
+
+            /// Returns the raw bits, including any unknown bits.
+            pub const fn bits(&self) -> $bits {
+                self.0
+            }
+
+            /// Constructs the flags from raw bits, keeping any unknown bits.
+            pub const fn from_bits_retain(bits: $bits) -> Self {
+                Self(bits)
+            }
+
+            /// Returns true if every bit of `other` is set.
+            pub const fn contains(&self, other: Self) -> bool {
+                self.0 & other.0 == other.0
+            }
+        }
+
+        impl $crate::types::Flags for $name {
+            type Bits = $bits;
+            const FLAGS: &'static [(&'static str, Self)] = &[$((stringify!($flag), Self::$flag)),*];
+
+            fn bits(&self) -> $bits {
+                self.0
+            }
+
+            fn from_bits_retain(bits: $bits) -> Self {
+                Self(bits)
+            }
+        }
+
+        impl ::core::ops::BitOr for $name {
+            type Output = Self;
+
+            fn bitor(self, rhs: Self) -> Self {
+                Self(self.0 | rhs.0)
+            }
+        }
+
+        impl ::core::ops::BitAnd for $name {
+            type Output = Self;
+
+            fn bitand(self, rhs: Self) -> Self {
+                Self(self.0 & rhs.0)
+            }
+        }
+    };
+}
+
+/// A mutable view of a flags type F stored as F::Bits with byte order E.
+///
+/// Every method reads or writes the underlying buffer directly. Bits which don't belong to any
+/// named flag are kept and can be inspected with [`FlagsView::unknown`].
+#[derive(Debug, PartialEq)]
+pub struct FlagsView<'a, F: Flags, E> {
+    view: MulByteView<'a, F::Bits, E>,
+}
+
+impl<'a, F: Flags, E: StaticEndian> FlagsView<'a, F, E> {
+    /// Returns a [`FlagsView`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `F::Bits.len() > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        let (view, leftover) = MulByteView::mut_view(arr);
+
+        (Self { view }, leftover)
+    }
+
+    /// Returns a [`FlagsView`] and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `F::Bits.len() > arr.len()`.
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let (view, leftover) = MulByteView::try_mut_view(arr)?;
+
+        Ok((Self { view }, leftover))
+    }
+}
+
+impl<'a, F: Flags, E> TryMutView<'a> for FlagsView<'a, F, E>
+where
+    MulByteView<'a, F::Bits, E>: TryMutView<'a>,
+{
+    fn try_mut_view_ctx(
+        arr: &'a mut [u8],
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let (view, leftover) = MulByteView::try_mut_view_ctx(arr, ctx)?;
+
+        Ok((Self { view }, leftover))
+    }
+}
+
+impl<'a, F: Flags, E> FlagsView<'a, F, E>
+where
+    MulByteView<'a, F::Bits, E>: ModMulByteView<'a, F::Bits, E>,
+{
+    fn raw(&self) -> u128 {
+        self.view.val().to_u128()
+    }
+
+    fn set_raw(&mut self, v: u128) {
+        self.view.set(F::Bits::from_u128(v))
+    }
+
+    /// Return a copy of the flags, including any unknown bits.
+    pub fn val(&self) -> F {
+        F::from_bits_retain(self.view.val())
+    }
+
+    /// Set the flags, including any unknown bits.
+    pub fn set(&mut self, v: F) {
+        self.view.set(v.bits())
+    }
+
+    /// Returns true if every bit of `flags` is set.
+    pub fn contains(&self, flags: F) -> bool {
+        let bits = flags.bits().to_u128();
+
+        self.raw() & bits == bits
+    }
+
+    /// Sets every bit of `flags`.
+    pub fn insert(&mut self, flags: F) {
+        self.set_raw(self.raw() | flags.bits().to_u128())
+    }
+
+    /// Clears every bit of `flags`.
+    pub fn remove(&mut self, flags: F) {
+        self.set_raw(self.raw() & !flags.bits().to_u128())
+    }
+
+    /// Flips every bit of `flags`.
+    pub fn toggle(&mut self, flags: F) {
+        self.set_raw(self.raw() ^ flags.bits().to_u128())
+    }
+
+    /// Returns the bits which don't belong to any named flag.
+    pub fn unknown(&self) -> F::Bits {
+        let known = F::FLAGS
+            .iter()
+            .fold(0, |acc, (_, f)| acc | f.bits().to_u128());
+
+        F::Bits::from_u128(self.raw() & !known)
+    }
+
+    /// Returns an iterator over the named flags which are set.
+    pub fn iter(&self) -> impl Iterator<Item = F> {
+        self.iter_names().map(|(_, f)| f)
+    }
+
+    /// Returns an iterator over the names and values of the named flags which are set.
+    pub fn iter_names(&self) -> impl Iterator<Item = (&'static str, F)> {
+        let raw = self.raw();
+
+        F::FLAGS.iter().copied().filter(move |(_, f)| {
+            let bits = f.bits().to_u128();
+
+            bits != 0 && raw & bits == bits
+        })
+    }
+}
+
+/// A read-only view of a flags type F stored as F::Bits with byte order E.
+///
+/// Bits which don't belong to any named flag can be inspected with [`FlagsRef::unknown`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct FlagsRef<'a, F: Flags, E> {
+    view: MulByteRef<'a, F::Bits, E>,
+}
+
+impl<'a, F: Flags, E: StaticEndian> FlagsRef<'a, F, E> {
+    /// Returns a [`FlagsRef`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `F::Bits.len() > arr.len()`
+    pub fn view(arr: &'a [u8]) -> (Self, &'a [u8]) {
+        let (view, leftover) = MulByteRef::view(arr);
+
+        (Self { view }, leftover)
+    }
+
+    /// Returns a [`FlagsRef`] and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `F::Bits.len() > arr.len()`.
+    pub fn try_view(arr: &'a [u8]) -> Result<(Self, &'a [u8]), ViewError> {
+        let (view, leftover) = MulByteRef::try_view(arr)?;
+
+        Ok((Self { view }, leftover))
+    }
+}
+
+impl<'a, F: Flags, E> TryView<'a> for FlagsRef<'a, F, E>
+where
+    MulByteRef<'a, F::Bits, E>: TryView<'a>,
+{
+    fn try_view_ctx(arr: &'a [u8], ctx: &ParseCtx) -> Result<(Self, &'a [u8]), ViewError> {
+        let (view, leftover) = MulByteRef::try_view_ctx(arr, ctx)?;
+
+        Ok((Self { view }, leftover))
+    }
+}
+
+impl<'a, F: Flags, E> FlagsRef<'a, F, E>
+where
+    MulByteRef<'a, F::Bits, E>: RefMulByteView<'a, F::Bits, E>,
+{
+    fn raw(&self) -> u128 {
+        self.view.val().to_u128()
+    }
+
+    /// Return a copy of the flags, including any unknown bits.
+    pub fn val(&self) -> F {
+        F::from_bits_retain(self.view.val())
+    }
+
+    /// Returns true if every bit of `flags` is set.
+    pub fn contains(&self, flags: F) -> bool {
+        let bits = flags.bits().to_u128();
+
+        self.raw() & bits == bits
+    }
+
+    /// Returns the bits which don't belong to any named flag.
+    pub fn unknown(&self) -> F::Bits {
+        let known = F::FLAGS
+            .iter()
+            .fold(0, |acc, (_, f)| acc | f.bits().to_u128());
+
+        F::Bits::from_u128(self.raw() & !known)
+    }
+
+    /// Returns an iterator over the named flags which are set.
+    pub fn iter(&self) -> impl Iterator<Item = F> {
+        self.iter_names().map(|(_, f)| f)
+    }
+
+    /// Returns an iterator over the names and values of the named flags which are set.
+    pub fn iter_names(&self) -> impl Iterator<Item = (&'static str, F)> {
+        let raw = self.raw();
+
+        F::FLAGS.iter().copied().filter(move |(_, f)| {
+            let bits = f.bits().to_u128();
+
+            bits != 0 && raw & bits == bits
+        })
+    }
+}
+
+impl<'a, F: Flags, E> LenValue for FlagsView<'a, F, E>
+where
+    MulByteView<'a, F::Bits, E>: ModMulByteView<'a, F::Bits, E>,
+{
+    fn len_value(&self) -> Option<usize> {
+        self.raw().try_into().ok()
+    }
+}
+
+impl<'a, F: Flags, E> LenValue for FlagsRef<'a, F, E>
+where
+    MulByteRef<'a, F::Bits, E>: RefMulByteView<'a, F::Bits, E>,
+{
+    fn len_value(&self) -> Option<usize> {
+        self.raw().try_into().ok()
+    }
+}
+
+impl<'a, F: Flags, E> EqValue for FlagsRef<'a, F, E>
+where
+    F::Bits: PartialEq + Into<FieldValue>,
+    MulByteRef<'a, F::Bits, E>: RefMulByteView<'a, F::Bits, E>,
+{
+    type Value = F::Bits;
+
+    fn eq_value(&self) -> F::Bits {
+        self.view.val()
+    }
+}
+
+impl<'a, F: Flags, E> FixedSize for FlagsView<'a, F, E> {
+    const SIZE: usize = core::mem::size_of::<F::Bits>();
+}
+
+impl<'a, F: Flags, E> FixedSize for FlagsRef<'a, F, E> {
+    const SIZE: usize = core::mem::size_of::<F::Bits>();
+}
+
+impl<'a, F: Flags, E> EqValue for FlagsView<'a, F, E>
+where
+    F::Bits: PartialEq + Into<FieldValue>,
+    MulByteView<'a, F::Bits, E>: ModMulByteView<'a, F::Bits, E>,
+{
+    type Value = F::Bits;
+
+    fn eq_value(&self) -> F::Bits {
+        self.view.val()
+    }
+}
+
+/// Implemented by C-like enums used as the En in [`EnumView<'a, En, Repr, E>`], usually via the
+/// [`EnumRepr`](crate::EnumRepr) derive.
+pub trait EnumRepr<Repr>: Sized {
+    /// Returns the variant whose discriminant is `v`, if there is one.
+    fn from_repr(v: Repr) -> Option<Self>;
+    /// Returns the discriminant.
+    fn to_repr(self) -> Repr;
+}
+
+/// A mutable view of an enum En stored as its discriminant of type Repr with byte order E.
+///
+/// Values which don't match any variant are kept untouched in the buffer and returned as the
+/// `Err` of [`EnumView::val`].
+#[derive(Debug, PartialEq)]
+pub struct EnumView<'a, En, Repr, E> {
+    view: MulByteView<'a, Repr, E>,
+    _type: core::marker::PhantomData<En>,
+}
+
+impl<'a, En, Repr, E: StaticEndian> EnumView<'a, En, Repr, E> {
+    /// Returns an [`EnumView`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Repr.len() > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        let (view, leftover) = MulByteView::mut_view(arr);
+
+        (
+            Self {
+                view,
+                _type: core::marker::PhantomData::<En>,
+            },
+            leftover,
+        )
+    }
+
+    /// Returns an [`EnumView`] and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `Repr.len() > arr.len()`.
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let (view, leftover) = MulByteView::try_mut_view(arr)?;
+
+        Ok((
+            Self {
+                view,
+                _type: core::marker::PhantomData::<En>,
+            },
+            leftover,
+        ))
+    }
+}
+
+impl<'a, En, Repr, E> TryMutView<'a> for EnumView<'a, En, Repr, E>
+where
+    MulByteView<'a, Repr, E>: TryMutView<'a>,
+{
+    fn try_mut_view_ctx(
+        arr: &'a mut [u8],
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let (view, leftover) = MulByteView::try_mut_view_ctx(arr, ctx)?;
+
+        Ok((
+            Self {
+                view,
+                _type: core::marker::PhantomData::<En>,
+            },
+            leftover,
+        ))
+    }
+}
+
+impl<'a, En, Repr, E> EnumView<'a, En, Repr, E>
+where
+    En: EnumRepr<Repr>,
+    Repr: Copy,
+    MulByteView<'a, Repr, E>: ModMulByteView<'a, Repr, E>,
+{
+    /// Returns the variant, or the raw discriminant if it doesn't match any variant.
+    pub fn val(&self) -> Result<En, Repr> {
+        let raw = self.raw();
+
+        En::from_repr(raw).ok_or(raw)
+    }
+
+    /// Returns the raw discriminant.
+    pub fn raw(&self) -> Repr {
+        self.view.val()
+    }
+
+    /// Writes the discriminant of `v`.
+    pub fn set(&mut self, v: En) {
+        self.view.set(v.to_repr())
+    }
+
+    /// Writes a raw discriminant, which doesn't need to match any variant.
+    pub fn set_raw(&mut self, v: Repr) {
+        self.view.set(v)
+    }
+}
+
+/// A read-only view of an enum En stored as its discriminant of type Repr with byte order E.
+///
+/// Values which don't match any variant are returned as the `Err` of [`EnumRef::val`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct EnumRef<'a, En, Repr, E> {
+    view: MulByteRef<'a, Repr, E>,
+    _type: core::marker::PhantomData<En>,
+}
+
+impl<'a, En, Repr, E: StaticEndian> EnumRef<'a, En, Repr, E> {
+    /// Returns an [`EnumRef`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `Repr.len() > arr.len()`
+    pub fn view(arr: &'a [u8]) -> (Self, &'a [u8]) {
+        let (view, leftover) = MulByteRef::view(arr);
+
+        (
+            Self {
+                view,
+                _type: core::marker::PhantomData::<En>,
+            },
+            leftover,
+        )
+    }
+
+    /// Returns an [`EnumRef`] and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `Repr.len() > arr.len()`.
+    pub fn try_view(arr: &'a [u8]) -> Result<(Self, &'a [u8]), ViewError> {
+        let (view, leftover) = MulByteRef::try_view(arr)?;
+
+        Ok((
+            Self {
+                view,
+                _type: core::marker::PhantomData::<En>,
+            },
+            leftover,
+        ))
+    }
+}
+
+impl<'a, En, Repr, E> TryView<'a> for EnumRef<'a, En, Repr, E>
+where
+    MulByteRef<'a, Repr, E>: TryView<'a>,
+{
+    fn try_view_ctx(arr: &'a [u8], ctx: &ParseCtx) -> Result<(Self, &'a [u8]), ViewError> {
+        let (view, leftover) = MulByteRef::try_view_ctx(arr, ctx)?;
+
+        Ok((
+            Self {
+                view,
+                _type: core::marker::PhantomData::<En>,
+            },
+            leftover,
+        ))
+    }
+}
+
+impl<'a, En, Repr, E> EnumRef<'a, En, Repr, E>
+where
+    En: EnumRepr<Repr>,
+    Repr: Copy,
+    MulByteRef<'a, Repr, E>: RefMulByteView<'a, Repr, E>,
+{
+    /// Returns the variant, or the raw discriminant if it doesn't match any variant.
+    pub fn val(&self) -> Result<En, Repr> {
+        let raw = self.raw();
+
+        En::from_repr(raw).ok_or(raw)
+    }
+
+    /// Returns the raw discriminant.
+    pub fn raw(&self) -> Repr {
+        self.view.val()
+    }
+}
+
+impl<'a, En, Repr, E> LenValue for EnumView<'a, En, Repr, E>
+where
+    En: EnumRepr<Repr>,
+    Repr: Copy + TryInto<usize>,
+    MulByteView<'a, Repr, E>: ModMulByteView<'a, Repr, E>,
+{
+    fn len_value(&self) -> Option<usize> {
+        self.raw().try_into().ok()
+    }
+}
+
+impl<'a, En, Repr, E> LenValue for EnumRef<'a, En, Repr, E>
+where
+    En: EnumRepr<Repr>,
+    Repr: Copy + TryInto<usize>,
+    MulByteRef<'a, Repr, E>: RefMulByteView<'a, Repr, E>,
+{
+    fn len_value(&self) -> Option<usize> {
+        self.raw().try_into().ok()
+    }
+}
+
+impl<'a, En, Repr, E> EqValue for EnumRef<'a, En, Repr, E>
+where
+    En: EnumRepr<Repr>,
+    Repr: Copy + PartialEq + Into<FieldValue>,
+    MulByteRef<'a, Repr, E>: RefMulByteView<'a, Repr, E>,
+{
+    type Value = Repr;
+
+    fn eq_value(&self) -> Repr {
+        self.raw()
+    }
+}
+
+impl<'a, En, Repr, E> FixedSize for EnumView<'a, En, Repr, E> {
+    const SIZE: usize = core::mem::size_of::<Repr>();
+}
+
+impl<'a, En, Repr, E> FixedSize for EnumRef<'a, En, Repr, E> {
+    const SIZE: usize = core::mem::size_of::<Repr>();
+}
+
+impl<'a, En, Repr, E> EqValue for EnumView<'a, En, Repr, E>
+where
+    En: EnumRepr<Repr>,
+    Repr: Copy + PartialEq + Into<FieldValue>,
+    MulByteView<'a, Repr, E>: ModMulByteView<'a, Repr, E>,
+{
+    type Value = Repr;
+
+    fn eq_value(&self) -> Repr {
+        self.raw()
+    }
+}
+
+/// Returns the index of the target of the `raw` offset in a base of `len` bytes starting at the
+/// offset of `ctx`.
+fn offset_start(
+    raw: u128,
+    len: usize,
+    ctx: &ParseCtx,
+    map: impl FnOnce(usize) -> Option<usize>,
+) -> Result<usize, ViewError> {
+    let invalid = |offset| ViewError {
+        path: ctx.path(),
+        ..ViewError::invalid_offset().offset_by(offset)
+    };
+    // An offset too large for a usize is reported truncated to one.
+    let raw = raw.try_into().map_err(|_| invalid(raw as usize))?;
+    let offset = map(raw).ok_or_else(|| invalid(raw))?;
+
+    match offset.checked_sub(ctx.offset()) {
+        Some(start) if start <= len => Ok(start),
+        _ => Err(invalid(offset)),
+    }
+}
+
+/// A mutable view of an offset stored as O with byte order E, pointing to a T elsewhere in a base
+/// buffer, such as the `e_lfanew` of a PE file.
+///
+/// The target is only viewed when followed, so the base can be any buffer holding it, e.g. the
+/// leftover of the struct holding the offset. Setting the offset retargets the view in place.
+#[derive(Debug, PartialEq)]
+pub struct OffsetView<'a, T, E, O = u32> {
+    view: MulByteView<'a, O, E>,
+    _type: core::marker::PhantomData<T>,
+}
+
+impl<'a, T, E: StaticEndian, O> OffsetView<'a, T, E, O> {
+    /// Returns an [`OffsetView`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `O.len() > arr.len()`
+    pub fn mut_view(arr: &'a mut [u8]) -> (Self, &'a mut [u8]) {
+        let (view, leftover) = MulByteView::mut_view(arr);
+
+        (
+            Self {
+                view,
+                _type: core::marker::PhantomData::<T>,
+            },
+            leftover,
+        )
+    }
+
+    /// Returns an [`OffsetView`] and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `O.len() > arr.len()`.
+    pub fn try_mut_view(arr: &'a mut [u8]) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let (view, leftover) = MulByteView::try_mut_view(arr)?;
+
+        Ok((
+            Self {
+                view,
+                _type: core::marker::PhantomData::<T>,
+            },
+            leftover,
+        ))
+    }
+}
+
+impl<'a, T, E, O> TryMutView<'a> for OffsetView<'a, T, E, O>
+where
+    MulByteView<'a, O, E>: TryMutView<'a>,
+{
+    fn try_mut_view_ctx(
+        arr: &'a mut [u8],
+        ctx: &ParseCtx,
+    ) -> Result<(Self, &'a mut [u8]), ViewError> {
+        let (view, leftover) = MulByteView::try_mut_view_ctx(arr, ctx)?;
+
+        Ok((
+            Self {
+                view,
+                _type: core::marker::PhantomData::<T>,
+            },
+            leftover,
+        ))
+    }
+}
+
+impl<'a, T, E, O: BitWord> OffsetView<'a, T, E, O>
+where
+    MulByteView<'a, O, E>: ModMulByteView<'a, O, E>,
+{
+    /// Return a copy of the raw offset.
+    pub fn val(&self) -> O {
+        self.view.val()
+    }
+
+    /// Set the raw offset.
+    pub fn set(&mut self, v: O) {
+        self.view.set(v)
+    }
+
+    /// Returns the offset, or `None` if it doesn't fit in a `usize`.
+    pub fn offset(&self) -> Option<usize> {
+        self.val().to_u128().try_into().ok()
+    }
+
+    /// Points the view at `offset`, returning an [`OutOfRange`] error if it doesn't fit in O.
+    pub fn try_set(&mut self, offset: usize) -> Result<(), OutOfRange> {
+        let max = u128::MAX >> (128 - O::BITS);
+
+        if offset as u128 > max {
+            return Err(OutOfRange {
+                value: offset as i128,
+                min: 0,
+                max: max.try_into().unwrap_or(i128::MAX),
+            });
+        }
+
+        self.set(O::from_u128(offset as u128));
+        Ok(())
+    }
+
+    /// Returns a view of the target at the offset from the start of `base`.
+    pub fn try_follow<'b>(&self, base: &'b mut [u8]) -> Result<T, ViewError>
+    where
+        T: TryMutView<'b>,
+    {
+        self.try_follow_ctx(base, &ParseCtx::default())
+    }
+
+    /// Returns a view of the target, where `base` starts at the offset of `ctx` in the buffer the
+    /// offset points into.
+    pub fn try_follow_ctx<'b>(&self, base: &'b mut [u8], ctx: &ParseCtx) -> Result<T, ViewError>
+    where
+        T: TryMutView<'b>,
+    {
+        self.try_follow_map(base, ctx, Some)
+    }
+
+    /// Returns a view of the target after translating the offset with `map`, e.g. from an RVA to
+    /// a file offset through a PE section table. An offset `map` returns `None` for fails with
+    /// [`ViewErrorKind::InvalidOffset`](crate::error::ViewErrorKind::InvalidOffset).
+    pub fn try_follow_map<'b, M>(
+        &self,
+        base: &'b mut [u8],
+        ctx: &ParseCtx,
+        map: M,
+    ) -> Result<T, ViewError>
+    where
+        T: TryMutView<'b>,
+        M: FnOnce(usize) -> Option<usize>,
+    {
+        let start = offset_start(self.val().to_u128(), base.len(), ctx, map)?;
+        let (view, _) = T::try_mut_view_ctx(&mut base[start..], &ctx.advance(start))?;
+
+        Ok(view)
+    }
+
+    /// Returns a read-only view of the target at the offset from the start of `base`.
+    pub fn try_follow_ref<'b>(&self, base: &'b [u8]) -> Result<T, ViewError>
+    where
+        T: TryView<'b>,
+    {
+        self.try_follow_ref_ctx(base, &ParseCtx::default())
+    }
+
+    /// Returns a read-only view of the target, where `base` starts at the offset of `ctx` in the
+    /// buffer the offset points into.
+    pub fn try_follow_ref_ctx<'b>(&self, base: &'b [u8], ctx: &ParseCtx) -> Result<T, ViewError>
+    where
+        T: TryView<'b>,
+    {
+        self.try_follow_ref_map(base, ctx, Some)
+    }
+
+    /// Returns a read-only view of the target after translating the offset with `map`.
+    pub fn try_follow_ref_map<'b, M>(
+        &self,
+        base: &'b [u8],
+        ctx: &ParseCtx,
+        map: M,
+    ) -> Result<T, ViewError>
+    where
+        T: TryView<'b>,
+        M: FnOnce(usize) -> Option<usize>,
+    {
+        let start = offset_start(self.val().to_u128(), base.len(), ctx, map)?;
+        let (view, _) = T::try_view_ctx(&base[start..], &ctx.advance(start))?;
+
+        Ok(view)
+    }
+}
+
+impl<'a, T, E, O: BitWord> LenValue for OffsetView<'a, T, E, O>
+where
+    MulByteView<'a, O, E>: ModMulByteView<'a, O, E>,
+{
+    fn len_value(&self) -> Option<usize> {
+        self.offset()
+    }
+}
+
+impl<'a, T, E, O> FixedSize for OffsetView<'a, T, E, O> {
+    const SIZE: usize = core::mem::size_of::<O>();
+}
+
+impl<'a, 'b, T, E, O> Reborrow<'b> for OffsetView<'a, T, E, O> {
+    type Target = OffsetView<'b, T, E, O>;
+}
+
+/// A read-only view of an offset stored as O with byte order E, pointing to a T elsewhere in a base
+/// buffer.
+///
+/// The read-only counterpart of [`OffsetView`]. Targets are followed with read-only views.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct OffsetRef<'a, T, E, O = u32> {
+    view: MulByteRef<'a, O, E>,
+    _type: core::marker::PhantomData<T>,
+}
+
+impl<'a, T, E: StaticEndian, O> OffsetRef<'a, T, E, O> {
+    /// Returns an [`OffsetRef`] and leftover slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `O.len() > arr.len()`
+    pub fn view(arr: &'a [u8]) -> (Self, &'a [u8]) {
+        let (view, leftover) = MulByteRef::view(arr);
+
+        (
+            Self {
+                view,
+                _type: core::marker::PhantomData::<T>,
+            },
+            leftover,
+        )
+    }
+
+    /// Returns an [`OffsetRef`] and leftover slice.
+    ///
+    /// Returns a [`ViewError`] if `O.len() > arr.len()`.
+    pub fn try_view(arr: &'a [u8]) -> Result<(Self, &'a [u8]), ViewError> {
+        let (view, leftover) = MulByteRef::try_view(arr)?;
+
+        Ok((
+            Self {
+                view,
+                _type: core::marker::PhantomData::<T>,
+            },
+            leftover,
+        ))
+    }
+}
+
+impl<'a, T, E, O> TryView<'a> for OffsetRef<'a, T, E, O>
+where
+    MulByteRef<'a, O, E>: TryView<'a>,
+{
+    fn try_view_ctx(arr: &'a [u8], ctx: &ParseCtx) -> Result<(Self, &'a [u8]), ViewError> {
+        let (view, leftover) = MulByteRef::try_view_ctx(arr, ctx)?;
+
+        Ok((
+            Self {
+                view,
+                _type: core::marker::PhantomData::<T>,
+            },
+            leftover,
+        ))
+    }
+}
+
+impl<'a, T, E, O: BitWord> OffsetRef<'a, T, E, O>
+where
+    MulByteRef<'a, O, E>: RefMulByteView<'a, O, E>,
+{
+    /// Return a copy of the raw offset.
+    pub fn val(&self) -> O {
+        self.view.val()
+    }
+
+    /// Returns the offset, or `None` if it doesn't fit in a `usize`.
+    pub fn offset(&self) -> Option<usize> {
+        self.val().to_u128().try_into().ok()
+    }
+
+    /// Returns a view of the target at the offset from the start of `base`.
+    pub fn try_follow<'b>(&self, base: &'b [u8]) -> Result<T, ViewError>
+    where
+        T: TryView<'b>,
+    {
+        self.try_follow_ctx(base, &ParseCtx::default())
+    }
+
+    /// Returns a view of the target, where `base` starts at the offset of `ctx` in the buffer the
+    /// offset points into.
+    pub fn try_follow_ctx<'b>(&self, base: &'b [u8], ctx: &ParseCtx) -> Result<T, ViewError>
+    where
+        T: TryView<'b>,
+    {
+        self.try_follow_map(base, ctx, Some)
+    }
+
+    /// Returns a view of the target after translating the offset with `map`. An offset `map`
+    /// returns `None` for fails with
+    /// [`ViewErrorKind::InvalidOffset`](crate::error::ViewErrorKind::InvalidOffset).
+    pub fn try_follow_map<'b, M>(
+        &self,
+        base: &'b [u8],
+        ctx: &ParseCtx,
+        map: M,
+    ) -> Result<T, ViewError>
+    where
+        T: TryView<'b>,
+        M: FnOnce(usize) -> Option<usize>,
+    {
+        let start = offset_start(self.val().to_u128(), base.len(), ctx, map)?;
+        let (view, _) = T::try_view_ctx(&base[start..], &ctx.advance(start))?;
+
+        Ok(view)
+    }
+}
+
+impl<'a, T, E, O: BitWord> LenValue for OffsetRef<'a, T, E, O>
+where
+    MulByteRef<'a, O, E>: RefMulByteView<'a, O, E>,
+{
+    fn len_value(&self) -> Option<usize> {
+        self.offset()
+    }
+}
+
+impl<'a, T, E, O> FixedSize for OffsetRef<'a, T, E, O> {
+    const SIZE: usize = core::mem::size_of::<O>();
+}